@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, PoeModuleConfig,
+	Signature, SudoConfig, SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -152,5 +152,13 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		poe_module: PoeModuleConfig {
+			// Seed a few sample claims so test networks don't start empty.
+			claims: vec![
+				(b"node-template sample claim #1".to_vec(), endowed_accounts[0].clone()),
+				(b"node-template sample claim #2".to_vec(), endowed_accounts[0].clone()),
+				(b"node-template sample claim #3".to_vec(), endowed_accounts[1].clone()),
+			],
+		},
 	}
 }
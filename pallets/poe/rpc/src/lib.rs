@@ -0,0 +1,179 @@
+//! pallet-poe 的 JSON-RPC 接口
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_poe::{ClaimLock, ClaimMetadata, NamespaceId, DEFAULT_NAMESPACE};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe::runtime_api::PoeApi as PoeRuntimeApi;
+
+/// `poe_getClaim` 返回的存证信息
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcClaim<AccountId, BlockNumber> {
+	/// 所有者
+	pub owner: AccountId,
+	/// 创建时的区块高度
+	pub created_at: BlockNumber,
+	/// 拥有者最后一次变更时的区块高度
+	pub updated_at: BlockNumber,
+	/// 过期的区块高度
+	pub expires_at: Option<BlockNumber>,
+	/// 元数据
+	pub metadata: Option<RpcClaimMetadata>,
+	/// 是否处于锁定期
+	pub locked: bool,
+	/// 锁定结束的区块高度, 永久锁定或未锁定时为 `null`
+	pub locked_until: Option<BlockNumber>,
+}
+
+/// 存证元数据, 文本字段按 UTF-8 解码
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcClaimMetadata {
+	/// MIME 类型
+	pub content_type: String,
+	/// 文件大小 (字节)
+	pub size: u64,
+	/// 描述
+	pub description: String,
+	/// 链下存储地址
+	pub uri: String,
+}
+
+impl From<ClaimMetadata<Vec<u8>, Vec<u8>, Vec<u8>>> for RpcClaimMetadata {
+	fn from(metadata: ClaimMetadata<Vec<u8>, Vec<u8>, Vec<u8>>) -> Self {
+		Self {
+			content_type: String::from_utf8_lossy(&metadata.content_type).into_owned(),
+			size: metadata.size,
+			description: String::from_utf8_lossy(&metadata.description).into_owned(),
+			uri: String::from_utf8_lossy(&metadata.uri).into_owned(),
+		}
+	}
+}
+
+/// 存证查询 RPC
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber> {
+	/// 查询命名空间中的存证, 不存在时返回 `null`; 未指定 `namespace` 时查询默认命名空间
+	#[method(name = "poe_getClaim")]
+	fn get_claim(
+		&self,
+		claim: Bytes,
+		namespace: Option<NamespaceId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcClaim<AccountId, BlockNumber>>>;
+
+	/// 分页列出 `who` 拥有的存证
+	#[method(name = "poe_claimsOf")]
+	fn claims_of(
+		&self,
+		who: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Bytes>>;
+}
+
+/// 错误码
+pub enum Error {
+	/// 调用 runtime API 失败
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// [`PoeApiServer`] 的实现
+pub struct Poe<C, Block, Balance, Hash> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(Block, Balance, Hash)>,
+}
+
+impl<C, Block, Balance, Hash> Poe<C, Block, Balance, Hash> {
+	/// 创建新的实例
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message.to_string(),
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, BlockNumber, Balance, Hash>
+	PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for Poe<C, Block, Balance, Hash>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Balance, Hash>,
+	AccountId: Codec + Clone + Send + Sync + 'static + Serialize + for<'de> Deserialize<'de>,
+	BlockNumber: Codec + Clone + Send + Sync + 'static + Serialize + for<'de> Deserialize<'de>,
+	Balance: Codec + Send + Sync + 'static,
+	Hash: Codec + Send + Sync + 'static,
+{
+	fn get_claim(
+		&self,
+		claim: Bytes,
+		namespace: Option<NamespaceId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RpcClaim<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = api
+			.get_claim(at_hash, namespace.unwrap_or(DEFAULT_NAMESPACE), claim.to_vec())
+			.map_err(|e| runtime_error("Unable to query claim.", e))?;
+		Ok(details.map(|d| {
+			let locked_until = match &d.lock {
+				Some(ClaimLock::Until(until)) => Some(until.clone()),
+				_ => None,
+			};
+			RpcClaim {
+				owner: d.owner,
+				created_at: d.created_at,
+				updated_at: d.updated_at,
+				expires_at: d.expires_at,
+				metadata: d.metadata.map(Into::into),
+				locked: d.lock.is_some(),
+				locked_until,
+			}
+		}))
+	}
+
+	fn claims_of(
+		&self,
+		who: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Bytes>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let claims = api
+			.claims_of(at_hash, who, offset, limit)
+			.map_err(|e| runtime_error("Unable to list claims.", e))?;
+		Ok(claims.into_iter().map(Into::into).collect())
+	}
+}
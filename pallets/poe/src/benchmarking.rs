@@ -0,0 +1,748 @@
+//! Benchmarking setup for pallet-poe
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Poe;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{Currency, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+use sp_std::{vec, vec::Vec};
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+fn claim_of_len<T: Config>(len: u32) -> BoundedVec<u8, T::MaxClaimLength> {
+	BoundedVec::try_from(vec![1u8; len as usize]).expect("len is within MaxClaimLength; qed")
+}
+
+/// 以 `index` 区分、长度为 `MaxClaimLength` 的存证
+fn max_len_claim<T: Config>(index: u32, fill: u8) -> ClaimOf<T> {
+	let mut data = vec![fill; T::MaxClaimLength::get() as usize];
+	data[..4].copy_from_slice(&index.to_le_bytes());
+	ClaimOf::<T>::try_from(data).expect("len is MaxClaimLength; qed")
+}
+
+/// `n` 个互不相同且长度为 `MaxClaimLength` 的存证
+fn max_len_claims<T: Config>(n: u32) -> BoundedVec<ClaimOf<T>, T::MaxClaimsPerBatch> {
+	let claims = (0..n).map(|i| max_len_claim::<T>(i, 0)).collect::<Vec<_>>();
+	BoundedVec::try_from(claims).expect("n is within MaxClaimsPerBatch; qed")
+}
+
+/// 在 `at` 区块的到期队列中预先放入 `count` 个存证, 只写队列本身
+fn fill_expiry_queue<T: Config>(at: T::BlockNumber, count: u32) {
+	let claims = (0..count).map(|i| max_len_claim::<T>(i, 0xff)).collect::<Vec<_>>();
+	ExpiryQueue::<T>::insert(
+		at,
+		BoundedVec::<_, T::MaxExpiringPerBlock>::try_from(claims)
+			.expect("count is within MaxExpiringPerBlock; qed"),
+	);
+}
+
+/// 各字段都达到最大长度的元数据
+fn max_metadata<T: Config>() -> MetadataOf<T> {
+	ClaimMetadata {
+		content_type: BoundedVec::try_from(vec![b'a'; T::MaxContentTypeLength::get() as usize])
+			.expect("len is MaxContentTypeLength; qed"),
+		size: u64::MAX,
+		description: BoundedVec::try_from(vec![b'd'; T::MaxDescriptionLength::get() as usize])
+			.expect("len is MaxDescriptionLength; qed"),
+		uri: BoundedVec::try_from(vec![b'u'; T::MaxUriLength::get() as usize])
+			.expect("len is MaxUriLength; qed"),
+	}
+}
+
+/// 为存证授权 `count` 个操作员
+fn fill_claim_operators<T: Config>(owner: &T::AccountId, claim: &ClaimOf<T>, count: u32) {
+	for i in 0..count {
+		Poe::<T>::approve_operator(
+			RawOrigin::Signed(owner.clone()).into(),
+			account("operator", i, 0),
+			Some(claim.clone()),
+		)
+		.expect("operator can be approved; qed");
+	}
+}
+
+/// `MaxSigners` 个签署人
+fn max_signers<T: Config>() -> SignersOf<T> {
+	let signers = (0..T::MaxSigners::get()).map(|i| account("signer", i, 0)).collect::<Vec<_>>();
+	SignersOf::<T>::try_from(signers).expect("len is MaxSigners; qed")
+}
+
+/// 由 `whitelisted_caller` 之外的账户创建的长度为 `len` 的存证
+fn claim_of_other<T: Config>(len: u32) -> ClaimOf<T> {
+	let owner: T::AccountId = account("owner", 0, 0);
+	T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+	let claim = claim_of_len::<T>(len);
+	Poe::<T>::create_claim(RawOrigin::Signed(owner).into(), claim.clone())
+		.expect("claim can be created; qed");
+	claim
+}
+
+/// `owner` 注册的未开放命名空间
+fn created_namespace<T: Config>(owner: &T::AccountId) -> NamespaceId {
+	let namespace = NextNamespaceId::<T>::get();
+	Poe::<T>::create_namespace(RawOrigin::Signed(owner.clone()).into(), false)
+		.expect("namespace can be created; qed");
+	namespace
+}
+
+fn next_block<T: Config>() -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number() + 1u32.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let claim = claim_of_len::<T>(l);
+
+		#[extrinsic_call]
+		create_claim(RawOrigin::Signed(caller.clone()), claim.clone());
+
+		assert_eq!(
+			Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner),
+			Some(caller)
+		);
+	}
+
+	#[benchmark]
+	fn revoke_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let claim = claim_of_len::<T>(l);
+		// 最坏情况: 存证带有效期且所在的到期队列已满
+		let expires_at = next_block::<T>();
+		fill_expiry_queue::<T>(expires_at, T::MaxExpiringPerBlock::get() - 1);
+		Poe::<T>::create_expiring_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			expires_at,
+		)
+		.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		revoke_claim(RawOrigin::Signed(caller), claim.clone());
+
+		assert!(!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+	}
+
+	#[benchmark]
+	fn transfer_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		T::Currency::make_free_balance_be(&dest, T::Currency::minimum_balance());
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		transfer_claim(RawOrigin::Signed(caller), dest.clone(), claim.clone());
+
+		assert_eq!(Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner), Some(dest));
+	}
+
+	#[benchmark]
+	fn create_claims(n: Linear<1, { T::MaxClaimsPerBatch::get() }>) {
+		let caller = funded_caller::<T>();
+		let claims = max_len_claims::<T>(n);
+
+		#[extrinsic_call]
+		create_claims(RawOrigin::Signed(caller), claims.clone());
+
+		assert!(claims.iter().all(|claim| Proofs::<T>::contains_key(DEFAULT_NAMESPACE, claim)));
+	}
+
+	#[benchmark]
+	fn revoke_claims(n: Linear<1, { T::MaxClaimsPerBatch::get() }>) {
+		let caller = funded_caller::<T>();
+		let claims = max_len_claims::<T>(n);
+		Poe::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), claims.clone())
+			.expect("claims can be created; qed");
+
+		#[extrinsic_call]
+		revoke_claims(RawOrigin::Signed(caller), claims.clone());
+
+		assert!(claims.iter().all(|claim| !Proofs::<T>::contains_key(DEFAULT_NAMESPACE, claim)));
+	}
+
+	#[benchmark]
+	fn transfer_claims(n: Linear<1, { T::MaxClaimsPerBatch::get() }>) {
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		T::Currency::make_free_balance_be(&dest, T::Currency::minimum_balance());
+		let claims = max_len_claims::<T>(n);
+		Poe::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), claims.clone())
+			.expect("claims can be created; qed");
+
+		#[extrinsic_call]
+		transfer_claims(RawOrigin::Signed(caller), dest.clone(), claims.clone());
+
+		assert!(claims.iter().all(|claim| {
+			Proofs::<T>::get(DEFAULT_NAMESPACE, claim).map(|info| info.owner) == Some(dest.clone())
+		}));
+	}
+
+	#[benchmark]
+	fn create_expiring_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let claim = claim_of_len::<T>(l);
+		let expires_at = next_block::<T>();
+		fill_expiry_queue::<T>(expires_at, T::MaxExpiringPerBlock::get() - 1);
+
+		#[extrinsic_call]
+		create_expiring_claim(RawOrigin::Signed(caller), claim.clone(), expires_at);
+
+		assert_eq!(ClaimExpiry::<T>::get(&claim), Some(expires_at));
+	}
+
+	#[benchmark]
+	fn expire_claims(n: Linear<0, { T::MaxExpiringPerBlock::get() }>) {
+		let caller = funded_caller::<T>();
+		let expires_at = next_block::<T>();
+		let claims = (0..n).map(|i| max_len_claim::<T>(i, 0)).collect::<Vec<_>>();
+		for claim in claims.iter() {
+			Poe::<T>::create_expiring_claim(
+				RawOrigin::Signed(caller.clone()).into(),
+				claim.clone(),
+				expires_at,
+			)
+			.expect("claim can be created; qed");
+		}
+
+		#[block]
+		{
+			Poe::<T>::on_initialize(expires_at);
+		}
+
+		assert!(claims.iter().all(|claim| !Proofs::<T>::contains_key(DEFAULT_NAMESPACE, claim)));
+	}
+
+	#[benchmark]
+	fn anchor_root() {
+		let caller = funded_caller::<T>();
+		let root = T::Hashing::hash(b"root");
+
+		#[extrinsic_call]
+		anchor_root(RawOrigin::Signed(caller), root, u32::MAX);
+
+		assert!(Anchors::<T>::contains_key(root));
+	}
+
+	#[benchmark]
+	fn remove_anchor() {
+		let caller = funded_caller::<T>();
+		let root = T::Hashing::hash(b"root");
+		Poe::<T>::anchor_root(RawOrigin::Signed(caller.clone()).into(), root, u32::MAX)
+			.expect("root can be anchored; qed");
+
+		#[extrinsic_call]
+		remove_anchor(RawOrigin::Signed(caller), root);
+
+		assert!(!Anchors::<T>::contains_key(root));
+	}
+
+	#[benchmark]
+	fn offer_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		offer_claim(RawOrigin::Signed(caller), dest, claim.clone(), Some(next_block::<T>()));
+
+		assert!(PendingTransfers::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn accept_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		T::Currency::make_free_balance_be(&dest, T::Currency::minimum_balance());
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+		Poe::<T>::offer_claim(
+			RawOrigin::Signed(caller).into(),
+			dest.clone(),
+			claim.clone(),
+			Some(next_block::<T>()),
+		)
+		.expect("claim can be offered; qed");
+
+		#[extrinsic_call]
+		accept_claim(RawOrigin::Signed(dest.clone()), claim.clone());
+
+		assert_eq!(Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner), Some(dest));
+		assert!(!PendingTransfers::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn cancel_offer(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+		Poe::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), dest, claim.clone(), None)
+			.expect("claim can be offered; qed");
+
+		#[extrinsic_call]
+		cancel_offer(RawOrigin::Signed(caller), claim.clone());
+
+		assert!(!PendingTransfers::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn set_claim_metadata() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		set_claim_metadata(RawOrigin::Signed(caller), claim.clone(), Some(max_metadata::<T>()));
+
+		assert!(ClaimMetadataOf::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn approve_operator() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+		fill_claim_operators::<T>(&caller, &claim, T::MaxOperators::get() - 1);
+		let operator: T::AccountId = account("operator", T::MaxOperators::get(), 0);
+
+		#[extrinsic_call]
+		approve_operator(RawOrigin::Signed(caller), operator.clone(), Some(claim.clone()));
+
+		assert!(ClaimOperators::<T>::get(&claim).contains(&operator));
+	}
+
+	#[benchmark]
+	fn revoke_operator() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+		fill_claim_operators::<T>(&caller, &claim, T::MaxOperators::get());
+		let operator: T::AccountId = account("operator", T::MaxOperators::get() - 1, 0);
+
+		#[extrinsic_call]
+		revoke_operator(RawOrigin::Signed(caller), operator.clone(), Some(claim.clone()));
+
+		assert!(!ClaimOperators::<T>::get(&claim).contains(&operator));
+	}
+
+	#[benchmark]
+	fn propose_cosigned_claim() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+
+		#[extrinsic_call]
+		propose_cosigned_claim(
+			RawOrigin::Signed(caller),
+			claim.clone(),
+			max_signers::<T>(),
+			next_block::<T>(),
+		);
+
+		assert!(PendingCosigns::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn cosign_claim() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+		let signers = max_signers::<T>();
+		Poe::<T>::propose_cosigned_claim(
+			RawOrigin::Signed(caller).into(),
+			claim.clone(),
+			signers.clone(),
+			next_block::<T>(),
+		)
+		.expect("cosigned claim can be proposed; qed");
+		let (last, others) = signers.split_last().expect("MaxSigners is not zero; qed");
+		for signer in others {
+			Poe::<T>::cosign_claim(RawOrigin::Signed(signer.clone()).into(), claim.clone())
+				.expect("signer can cosign; qed");
+		}
+
+		// 最后一个签署人使存证生效
+		#[extrinsic_call]
+		cosign_claim(RawOrigin::Signed(last.clone()), claim.clone());
+
+		assert_eq!(ClaimSigners::<T>::get(&claim), Some(signers));
+	}
+
+	#[benchmark]
+	fn cancel_cosigned_claim() {
+		let caller = funded_caller::<T>();
+		let claim = max_len_claim::<T>(0, 0);
+		Poe::<T>::propose_cosigned_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			max_signers::<T>(),
+			next_block::<T>(),
+		)
+		.expect("cosigned claim can be proposed; qed");
+
+		#[extrinsic_call]
+		cancel_cosigned_claim(RawOrigin::Signed(caller), claim.clone());
+
+		assert!(!PendingCosigns::<T>::contains_key(&claim));
+	}
+
+	#[benchmark]
+	fn create_claim_attested(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let attester = T::BenchmarkHelper::attester();
+		Attesters::<T>::insert(&attester, ());
+		let payload = AttestationPayload {
+			attester: attester.clone(),
+			claim: claim_of_len::<T>(l),
+			nonce: 0,
+		};
+		let signature =
+			T::BenchmarkHelper::sign(&attester, &Poe::<T>::attestation_message(&payload));
+
+		#[extrinsic_call]
+		create_claim_attested(RawOrigin::None, payload.clone(), signature);
+
+		assert_eq!(
+			Proofs::<T>::get(DEFAULT_NAMESPACE, &payload.claim).map(|info| info.owner),
+			Some(attester)
+		);
+	}
+
+	#[benchmark]
+	fn register_attester() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attester: T::AccountId = account("attester", 0, 0);
+
+		#[extrinsic_call]
+		register_attester(origin as T::RuntimeOrigin, attester.clone());
+
+		assert!(Attesters::<T>::contains_key(&attester));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_attester() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let attester: T::AccountId = account("attester", 0, 0);
+		Attesters::<T>::insert(&attester, ());
+
+		#[extrinsic_call]
+		remove_attester(origin as T::RuntimeOrigin, attester.clone());
+
+		assert!(!Attesters::<T>::contains_key(&attester));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn supersede_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let old = max_len_claim::<T>(0, 0xaa);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), old.clone())
+			.expect("claim can be created; qed");
+		let new = claim_of_len::<T>(l);
+
+		#[extrinsic_call]
+		supersede_claim(RawOrigin::Signed(caller), old.clone(), new.clone());
+
+		assert_eq!(SupersededBy::<T>::get(&old), Some(new));
+	}
+
+	#[benchmark]
+	fn commit_claim() {
+		let caller = funded_caller::<T>();
+		let commitment = T::Hashing::hash(b"commitment");
+
+		#[extrinsic_call]
+		commit_claim(RawOrigin::Signed(caller), commitment);
+
+		assert!(Commitments::<T>::contains_key(commitment));
+	}
+
+	#[benchmark]
+	fn reveal_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let salt = [0u8; 32];
+		let commitment = Poe::<T>::commitment_of(&claim_of_len::<T>(l), &salt);
+		Poe::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment)
+			.expect("commitment can be made; qed");
+		// 最坏情况: 取代承诺之后由其他账户抢先创建的同名存证
+		frame_system::Pallet::<T>::set_block_number(next_block::<T>());
+		let claim = claim_of_other::<T>(l);
+
+		#[extrinsic_call]
+		reveal_claim(RawOrigin::Signed(caller.clone()), claim.clone(), salt);
+
+		assert_eq!(
+			Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner),
+			Some(caller)
+		);
+		assert!(!Commitments::<T>::contains_key(commitment));
+	}
+
+	#[benchmark]
+	fn expire_commitments(n: Linear<0, { T::MaxCommitmentsPerBlock::get() }>) {
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::RevealPeriod::get();
+		let commitments = (0..n).map(|i| T::Hashing::hash_of(&i)).collect::<Vec<_>>();
+		// 每个账户的承诺数量有上限, 由不同账户提交
+		for (i, commitment) in commitments.iter().enumerate() {
+			let committer: T::AccountId = account("committer", i as u32, 0);
+			T::Currency::make_free_balance_be(
+				&committer,
+				BalanceOf::<T>::max_value() / 2u32.into(),
+			);
+			Poe::<T>::commit_claim(RawOrigin::Signed(committer).into(), *commitment)
+				.expect("commitment can be made; qed");
+		}
+
+		#[block]
+		{
+			Poe::<T>::on_initialize(expires_at);
+		}
+
+		assert!(commitments.iter().all(|commitment| !Commitments::<T>::contains_key(commitment)));
+	}
+
+	#[benchmark]
+	fn create_namespace() {
+		let caller = funded_caller::<T>();
+		let namespace = NextNamespaceId::<T>::get();
+
+		#[extrinsic_call]
+		create_namespace(RawOrigin::Signed(caller.clone()), false);
+
+		assert_eq!(Namespaces::<T>::get(namespace).map(|info| info.owner), Some(caller));
+	}
+
+	#[benchmark]
+	fn set_namespace_open() {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+
+		#[extrinsic_call]
+		set_namespace_open(RawOrigin::Signed(caller), namespace, true);
+
+		assert_eq!(Namespaces::<T>::get(namespace).map(|info| info.open), Some(true));
+	}
+
+	#[benchmark]
+	fn add_namespace_writer() {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+		let writer: T::AccountId = account("writer", 0, 0);
+
+		#[extrinsic_call]
+		add_namespace_writer(RawOrigin::Signed(caller), namespace, writer.clone());
+
+		assert!(NamespaceWriters::<T>::contains_key(namespace, &writer));
+	}
+
+	#[benchmark]
+	fn remove_namespace_writer() {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+		let writer: T::AccountId = account("writer", 0, 0);
+		Poe::<T>::add_namespace_writer(
+			RawOrigin::Signed(caller.clone()).into(),
+			namespace,
+			writer.clone(),
+		)
+		.expect("writer can be added; qed");
+
+		#[extrinsic_call]
+		remove_namespace_writer(RawOrigin::Signed(caller), namespace, writer.clone());
+
+		assert!(!NamespaceWriters::<T>::contains_key(namespace, &writer));
+	}
+
+	#[benchmark]
+	fn create_namespaced_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		// 最坏情况: 调用者既不是拥有者, 命名空间也未开放, 需要查询写入者
+		let owner: T::AccountId = account("owner", 0, 0);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		let namespace = created_namespace::<T>(&owner);
+		let caller = funded_caller::<T>();
+		Poe::<T>::add_namespace_writer(RawOrigin::Signed(owner).into(), namespace, caller.clone())
+			.expect("writer can be added; qed");
+		let claim = claim_of_len::<T>(l);
+
+		#[extrinsic_call]
+		create_namespaced_claim(RawOrigin::Signed(caller.clone()), namespace, claim.clone());
+
+		assert_eq!(Proofs::<T>::get(namespace, &claim).map(|info| info.owner), Some(caller));
+	}
+
+	#[benchmark]
+	fn revoke_namespaced_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_namespaced_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			namespace,
+			claim.clone(),
+		)
+		.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		revoke_namespaced_claim(RawOrigin::Signed(caller), namespace, claim.clone());
+
+		assert!(!Proofs::<T>::contains_key(namespace, &claim));
+	}
+
+	#[benchmark]
+	fn transfer_namespaced_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_namespaced_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			namespace,
+			claim.clone(),
+		)
+		.expect("claim can be created; qed");
+		let dest: T::AccountId = account("dest", 0, 0);
+
+		#[extrinsic_call]
+		transfer_namespaced_claim(
+			RawOrigin::Signed(caller),
+			namespace,
+			dest.clone(),
+			claim.clone(),
+		);
+
+		assert_eq!(Proofs::<T>::get(namespace, &claim).map(|info| info.owner), Some(dest));
+	}
+
+	#[benchmark]
+	fn remove_namespace() {
+		let caller = funded_caller::<T>();
+		let namespace = created_namespace::<T>(&caller);
+
+		#[extrinsic_call]
+		remove_namespace(RawOrigin::Signed(caller), namespace);
+
+		assert!(!Namespaces::<T>::contains_key(namespace));
+	}
+
+	#[benchmark]
+	fn challenge_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let claim = claim_of_other::<T>(l);
+		let caller = funded_caller::<T>();
+
+		#[extrinsic_call]
+		challenge_claim(RawOrigin::Signed(caller.clone()), claim.clone());
+
+		assert_eq!(
+			Challenges::<T>::get(&claim).map(|challenge| challenge.challenger),
+			Some(caller)
+		);
+	}
+
+	#[benchmark]
+	fn resolve_challenge(l: Linear<0, { T::MaxClaimLength::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ArbiterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let claim = claim_of_other::<T>(l);
+		let caller = funded_caller::<T>();
+		Poe::<T>::challenge_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be challenged; qed");
+
+		// 挑战成立时需要转移存证, 是最坏情况
+		#[extrinsic_call]
+		resolve_challenge(origin as T::RuntimeOrigin, claim.clone(), ChallengeVerdict::Upheld);
+
+		assert_eq!(
+			Proofs::<T>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner),
+			Some(caller)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn lock_claim(l: Linear<0, { T::MaxClaimLength::get() }>) {
+		let caller = funded_caller::<T>();
+		let claim = claim_of_len::<T>(l);
+		Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone())
+			.expect("claim can be created; qed");
+
+		#[extrinsic_call]
+		lock_claim(RawOrigin::Signed(caller), claim.clone(), None);
+
+		assert_eq!(ClaimLocks::<T>::get(&claim), Some(ClaimLock::Forever));
+	}
+
+	#[benchmark]
+	fn fund_sponsorship() {
+		let caller = funded_caller::<T>();
+		let amount = T::Currency::minimum_balance();
+
+		#[extrinsic_call]
+		fund_sponsorship(RawOrigin::Signed(caller.clone()), amount);
+
+		assert_eq!(SponsorBudgets::<T>::get(&caller), amount);
+	}
+
+	#[benchmark]
+	fn withdraw_sponsorship() {
+		let caller = funded_caller::<T>();
+		let amount = T::Currency::minimum_balance();
+		Poe::<T>::fund_sponsorship(RawOrigin::Signed(caller.clone()).into(), amount)
+			.expect("budget can be reserved; qed");
+
+		#[extrinsic_call]
+		withdraw_sponsorship(RawOrigin::Signed(caller.clone()), amount);
+
+		assert!(!SponsorBudgets::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn sponsor_account() {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("sponsee", 0, 0);
+
+		#[extrinsic_call]
+		sponsor_account(RawOrigin::Signed(caller.clone()), who.clone(), 10);
+
+		assert_eq!(
+			Sponsorships::<T>::get(&who),
+			Some(Sponsorship { sponsor: caller, remaining: 10 })
+		);
+	}
+
+	#[benchmark]
+	fn unsponsor_account() {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("sponsee", 0, 0);
+		Poe::<T>::sponsor_account(RawOrigin::Signed(caller.clone()).into(), who.clone(), 10)
+			.expect("account can be sponsored; qed");
+
+		#[extrinsic_call]
+		unsponsor_account(RawOrigin::Signed(caller), who.clone());
+
+		assert!(!Sponsorships::<T>::contains_key(&who));
+	}
+
+	impl_benchmark_test_suite!(Poe, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,46 @@
+//! 基于哈希摘要的存证
+//!
+//! 客户端在本地计算文件的哈希, 只把固定长度的摘要提交上链, 同一份文件在同一算法下总是得到相同
+//! 的存证。摘要以 SCALE 编码 (1 字节算法标识 + 32 字节哈希) 作为 `Proofs` 的键。
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// 带算法标识的文件摘要
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimDigest {
+	/// Blake2b-256
+	Blake2_256([u8; 32]),
+	/// SHA2-256
+	Sha2_256([u8; 32]),
+	/// Keccak-256
+	Keccak256([u8; 32]),
+}
+
+impl ClaimDigest {
+	/// 使用 Blake2b-256 计算 `data` 的摘要
+	pub fn blake2_256(data: &[u8]) -> Self {
+		Self::Blake2_256(sp_io::hashing::blake2_256(data))
+	}
+
+	/// 使用 SHA2-256 计算 `data` 的摘要
+	pub fn sha2_256(data: &[u8]) -> Self {
+		Self::Sha2_256(sp_io::hashing::sha2_256(data))
+	}
+
+	/// 使用 Keccak-256 计算 `data` 的摘要
+	pub fn keccak_256(data: &[u8]) -> Self {
+		Self::Keccak256(sp_io::hashing::keccak_256(data))
+	}
+
+	/// 用同一算法重新计算 `data` 的摘要并与自身比较
+	pub fn verify(&self, data: &[u8]) -> bool {
+		let computed = match self {
+			Self::Blake2_256(_) => Self::blake2_256(data),
+			Self::Sha2_256(_) => Self::sha2_256(data),
+			Self::Keccak256(_) => Self::keccak_256(data),
+		};
+		computed == *self
+	}
+}
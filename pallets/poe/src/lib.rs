@@ -8,10 +8,279 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod digest;
+pub mod merkle;
+pub mod migrations;
+pub mod runtime_api;
+pub mod sponsored;
+pub mod weights;
+pub use digest::ClaimDigest;
+pub use sponsored::ChargeSponsoredFee;
+pub use weights::*;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Currency;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// 存证内容
+pub type ClaimOf<T> = frame_support::BoundedVec<u8, <T as Config>::MaxClaimLength>;
+
+/// 命名空间的编号
+pub type NamespaceId = u32;
+
+/// 已授权的操作员
+pub type OperatorsOf<T> =
+	frame_support::BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxOperators>;
+
+/// 共同签署存证的签署人
+pub type SignersOf<T> =
+	frame_support::BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxSigners>;
+
+/// 存证的元数据
+pub type MetadataOf<T> = ClaimMetadata<
+	frame_support::BoundedVec<u8, <T as Config>::MaxContentTypeLength>,
+	frame_support::BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
+	frame_support::BoundedVec<u8, <T as Config>::MaxUriLength>,
+>;
+
+/// 存证所代表内容的描述
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimMetadata<ContentType, Description, Uri> {
+	/// MIME 类型, 如 `application/pdf`
+	pub content_type: ContentType,
+	/// 文件大小 (字节)
+	pub size: u64,
+	/// 描述
+	pub description: Description,
+	/// 链下存储地址, 如 IPFS CID
+	pub uri: Uri,
+}
+
+/// 存证转移的方式
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TransferKind {
+	/// 拥有者直接转移
+	Direct,
+	/// 接收者接受了拥有者发起的转移
+	Accepted,
+	/// 由 `ForceOrigin` 强制转移
+	Forced,
+	/// 挑战成立, 由仲裁转移给挑战者
+	Disputed,
+}
+
+/// 存证的一条所有权记录
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OwnershipRecord<AccountId, BlockNumber> {
+	/// 转出前的拥有者
+	pub owner: AccountId,
+	/// 转出时的区块高度
+	pub block: BlockNumber,
+	/// 转移方式
+	pub kind: TransferKind,
+}
+
+/// 已锚定的 Merkle 根的信息
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AnchorInfo<AccountId, BlockNumber, Balance> {
+	/// 提交者
+	pub who: AccountId,
+	/// 叶子数量
+	pub leaf_count: u32,
+	/// 锚定时的区块高度
+	pub block: BlockNumber,
+	/// 锁定的押金
+	pub deposit: Balance,
+}
+
+/// 待接受的存证转移
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, BlockNumber> {
+	/// 发起转移的拥有者
+	pub from: AccountId,
+	/// 接收者
+	pub to: AccountId,
+	/// 超过该区块高度后不能再接受
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// 等待签署的共同签署存证
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CosignProposal<AccountId, Signers, BlockNumber, Balance> {
+	/// 发起者, 存证生效后成为拥有者
+	pub creator: AccountId,
+	/// 需要签署的账户
+	pub signers: Signers,
+	/// 已签署的账户
+	pub signed: Signers,
+	/// 签署截止的区块高度
+	pub deadline: BlockNumber,
+	/// 发起者锁定的押金
+	pub deposit: Balance,
+}
+
+/// 存证记录
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+	/// 拥有者
+	pub owner: AccountId,
+	/// 创建时的区块高度
+	pub created_at: BlockNumber,
+	/// 拥有者最后一次变更时的区块高度
+	pub updated_at: BlockNumber,
+	/// 锁定的押金
+	pub deposit: Balance,
+}
+
+/// 已注册的命名空间
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NamespaceInfo<AccountId, Balance> {
+	/// 拥有者, 可以管理写入者
+	pub owner: AccountId,
+	/// 为 `true` 时任何账户都可以在其中创建存证, 否则只有拥有者和写入者可以
+	pub open: bool,
+	/// 锁定的押金
+	pub deposit: Balance,
+}
+
+/// 存证锁定的期限
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimLock<BlockNumber> {
+	/// 永久锁定
+	Forever,
+	/// 锁定到该区块高度为止 (不含)
+	Until(BlockNumber),
+}
+
+impl<BlockNumber: PartialOrd> ClaimLock<BlockNumber> {
+	/// 在区块 `now` 时是否仍然锁定
+	pub fn is_active(&self, now: &BlockNumber) -> bool {
+		match self {
+			Self::Forever => true,
+			Self::Until(until) => now < until,
+		}
+	}
+}
+
+/// 尚未裁决的存证挑战
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Challenge<AccountId, BlockNumber, Balance> {
+	/// 挑战者, 挑战成立后成为拥有者
+	pub challenger: AccountId,
+	/// 挑战时的区块高度
+	pub created_at: BlockNumber,
+	/// 锁定的保证金
+	pub bond: Balance,
+}
+
+/// 对存证挑战的裁决
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ChallengeVerdict {
+	/// 驳回挑战, 罚没挑战者的保证金
+	Dismissed,
+	/// 挑战成立, 罚没拥有者的存证押金并将存证转移给挑战者
+	Upheld,
+}
+
+/// 代付人为账户提供的手续费代付
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<AccountId> {
+	/// 代付人, 从其代付预算中支付手续费
+	pub sponsor: AccountId,
+	/// 剩余的代付次数
+	pub remaining: u32,
+}
+
+/// 尚未公开的存证承诺
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Commitment<AccountId, BlockNumber, Balance> {
+	/// 提交承诺的账户, 公开后成为存证拥有者
+	pub who: AccountId,
+	/// 提交承诺时的区块高度, 即存证的创建时间
+	pub committed_at: BlockNumber,
+	/// 在该区块之前必须公开, 否则承诺被清除
+	pub expires_at: BlockNumber,
+	/// 锁定的押金
+	pub deposit: Balance,
+}
+
+/// 证明人签名的存证内容
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AttestationPayload<AccountId, Claim> {
+	/// 证明人, 存证生效后成为拥有者
+	pub attester: AccountId,
+	/// 存证
+	pub claim: Claim,
+	/// 证明人的序号, 防止签名被重放
+	pub nonce: u64,
+}
+
+/// 为基准测试生成证明人及其签名
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+	/// 可以签名的证明人账户
+	fn attester() -> AccountId;
+	/// `attester` 对 `message` 的签名
+	fn sign(attester: &AccountId, message: &[u8]) -> Signature;
+}
+
+/// 存证详情, 供 runtime API 查询使用
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClaimDetails<AccountId, BlockNumber, Balance> {
+	/// 所有者
+	pub owner: AccountId,
+	/// 创建时的区块高度
+	pub created_at: BlockNumber,
+	/// 拥有者最后一次变更时的区块高度
+	pub updated_at: BlockNumber,
+	/// 锁定的押金
+	pub deposit: Balance,
+	/// 过期的区块高度
+	pub expires_at: Option<BlockNumber>,
+	/// 元数据
+	pub metadata: Option<ClaimMetadata<Vec<u8>, Vec<u8>, Vec<u8>>>,
+	/// 取代该存证的新版本, 为 `None` 时该存证是最新版本
+	pub superseded_by: Option<Vec<u8>>,
+	/// 仍然有效的锁定, 锁定期间存证不能撤销或转移
+	pub lock: Option<ClaimLock<BlockNumber>>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, Imbalance, OnUnbalanced, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{CheckedSub, Hash, IdentifyAccount, Saturating, Verify, Zero};
+
+	/// 证明人签名消息的前缀
+	pub const ATTESTATION_CONTEXT: &[u8] = b"poe/attestation";
+	/// 证明人存证交易在交易池中的有效期 (区块数)
+	const ATTESTATION_LONGEVITY: u64 = 64;
+
+	/// 当前的存储版本
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	/// 摘要存证编码后的长度
+	pub const DIGEST_CLAIM_LEN: u32 = 33;
+
+	/// 默认命名空间, 不属于任何账户, 命名空间之外的存证操作都在其中进行
+	///
+	/// 过期、元数据、操作员、锁定、挑战、共同签署和版本只适用于默认命名空间中的存证,
+	/// 其他命名空间中的存证只能由拥有者创建、撤销和转移
+	pub const DEFAULT_NAMESPACE: NamespaceId = 0;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -19,27 +288,489 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxClaimLength: Get<u32>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// 用于锁定存证押金的货币
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// 每个存证的基础押金
+		#[pallet::constant]
+		type ClaimDepositBase: Get<BalanceOf<Self>>;
+
+		/// 存证内容每字节的押金
+		#[pallet::constant]
+		type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// 批量操作中存证数量的上限
+		#[pallet::constant]
+		type MaxClaimsPerBatch: Get<u32>;
+
+		/// 存证有效期的上限 (区块数)
+		#[pallet::constant]
+		type MaxClaimLifetime: Get<Self::BlockNumber>;
+
+		/// 同一区块内到期的存证数量上限, 同时限定了 `on_initialize` 清理的权重
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+
+		/// 每个账户可拥有的存证数量上限
+		#[pallet::constant]
+		type MaxClaimsPerAccount: Get<u32>;
+
+		/// 是否允许 `transfer_claim` 不经接收者同意直接转移存证
+		#[pallet::constant]
+		type AllowDirectTransfer: Get<bool>;
+
+		/// 元数据中 MIME 类型的最大长度
+		#[pallet::constant]
+		type MaxContentTypeLength: Get<u32>;
+
+		/// 元数据中描述的最大长度
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// 元数据中链下地址的最大长度
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+
+		/// 每份元数据的基础押金
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// 元数据每字节的押金
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// 每个存证保留的所有权记录数量上限, 达到上限后丢弃最早的记录
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+
+		/// 每个账户或存证可授权的操作员数量上限
+		#[pallet::constant]
+		type MaxOperators: Get<u32>;
+
+		/// 可以强制创建、吊销和转移存证的 origin
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// 共同签署存证的签署人数量上限
+		#[pallet::constant]
+		type MaxSigners: Get<u32>;
+
+		/// 共同签署存证的签署期限上限 (区块数), 过期的提案不再占用存证
+		#[pallet::constant]
+		type MaxCosignPeriod: Get<Self::BlockNumber>;
+
+		/// 证明人的公钥
+		type AttesterPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// 证明人对存证的签名
+		type AttesterSignature: Verify<Signer = Self::AttesterPublic> + Parameter;
+
+		/// 证明人存证交易 (无签名交易) 的优先级
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// 基准测试中生成证明人签名
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::AttesterSignature>;
+
+		/// 提交存证承诺需要锁定的押金
+		#[pallet::constant]
+		type CommitmentDeposit: Get<BalanceOf<Self>>;
+
+		/// 提交承诺后公开存证的期限 (区块数)
+		#[pallet::constant]
+		type RevealPeriod: Get<Self::BlockNumber>;
+
+		/// 每个区块到期的承诺数量上限
+		#[pallet::constant]
+		type MaxCommitmentsPerBlock: Get<u32>;
+
+		/// 每个账户尚未公开的承诺数量上限, 应小于 `MaxCommitmentsPerBlock`
+		#[pallet::constant]
+		type MaxCommitmentsPerAccount: Get<u32>;
+
+		/// 注册命名空间需要锁定的押金
+		#[pallet::constant]
+		type NamespaceDeposit: Get<BalanceOf<Self>>;
+
+		/// 可以裁决存证挑战的 origin
+		type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// 挑战存证需要锁定的保证金
+		#[pallet::constant]
+		type ChallengeBond: Get<BalanceOf<Self>>;
+
+		/// 处理被罚没的保证金和押金
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// 本模块 extrinsics 的权重
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// maps 存证 to 拥有者和创建时的区块高度
+	/// maps (命名空间, 存证) to 存证记录
 	#[pallet::storage]
 	#[pallet::getter(fn proofs)]
-	pub type Proofs<T: Config> = StorageMap<
+	pub type Proofs<T: Config> = StorageDoubleMap<
 		_,
+		Twox64Concat,
+		NamespaceId,
 		Blake2_128Concat,
 		BoundedVec<u8, T::MaxClaimLength>,
-		(T::AccountId, T::BlockNumber),
+		ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+	>;
+
+	/// maps 命名空间 to 命名空间信息
+	#[pallet::storage]
+	#[pallet::getter(fn namespaces)]
+	pub type Namespaces<T: Config> =
+		StorageMap<_, Twox64Concat, NamespaceId, NamespaceInfo<T::AccountId, BalanceOf<T>>>;
+
+	#[pallet::type_value]
+	pub fn FirstNamespaceId() -> NamespaceId {
+		DEFAULT_NAMESPACE + 1
+	}
+
+	/// 下一个注册的命名空间的编号
+	#[pallet::storage]
+	pub type NextNamespaceId<T: Config> =
+		StorageValue<_, NamespaceId, ValueQuery, FirstNamespaceId>;
+
+	/// 命名空间中除拥有者外可以创建存证的账户
+	#[pallet::storage]
+	#[pallet::getter(fn namespace_writers)]
+	pub type NamespaceWriters<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// 账户在默认命名空间中拥有的存证索引
+	#[pallet::storage]
+	pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ClaimOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// maps 账户 to 在默认命名空间中拥有的存证数量, 与 `ClaimsByOwner` 一致
+	#[pallet::storage]
+	#[pallet::getter(fn claim_count)]
+	pub type ClaimCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// maps 账户 to 在其他命名空间中拥有的存证数量, 同样受 `MaxClaimsPerAccount` 限制
+	#[pallet::storage]
+	#[pallet::getter(fn namespaced_claim_count)]
+	pub type NamespacedClaimCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// maps 存证 to 到期的区块高度
+	#[pallet::storage]
+	#[pallet::getter(fn claim_expiry)]
+	pub type ClaimExpiry<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, T::BlockNumber>;
+
+	/// maps 区块高度 to 在该区块到期的存证
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<ClaimOf<T>, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// maps 存证 to 元数据和为其锁定的押金
+	#[pallet::storage]
+	#[pallet::getter(fn claim_metadata)]
+	pub type ClaimMetadataOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimOf<T>, (MetadataOf<T>, BalanceOf<T>)>;
+
+	/// maps 存证 to 按时间顺序排列的历任拥有者, 只保留最近的 `MaxHistoryLength` 条
+	#[pallet::storage]
+	pub type ClaimHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimOf<T>,
+		BoundedVec<OwnershipRecord<T::AccountId, T::BlockNumber>, T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	/// maps 账户 to 可代其管理全部存证的操作员
+	#[pallet::storage]
+	#[pallet::getter(fn operators)]
+	pub type Operators<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OperatorsOf<T>, ValueQuery>;
+
+	/// maps 存证 to 只能管理该存证的操作员, 存证转移后失效
+	#[pallet::storage]
+	#[pallet::getter(fn claim_operators)]
+	pub type ClaimOperators<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimOf<T>, OperatorsOf<T>, ValueQuery>;
+
+	/// maps 存证 to 等待签署的共同签署存证
+	#[pallet::storage]
+	#[pallet::getter(fn pending_cosigns)]
+	pub type PendingCosigns<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimOf<T>,
+		CosignProposal<T::AccountId, SignersOf<T>, T::BlockNumber, BalanceOf<T>>,
+	>;
+
+	/// maps 存证 to 共同签署该存证的账户
+	#[pallet::storage]
+	#[pallet::getter(fn claim_signers)]
+	pub type ClaimSigners<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, SignersOf<T>>;
+
+	/// 已登记的证明人
+	#[pallet::storage]
+	#[pallet::getter(fn attesters)]
+	pub type Attesters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// maps 证明人 to 下一个可用的序号, 取消登记后保留以防重放
+	#[pallet::storage]
+	#[pallet::getter(fn attester_nonce)]
+	pub type AttesterNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// maps 承诺哈希 to 尚未公开的存证承诺
+	#[pallet::storage]
+	#[pallet::getter(fn commitments)]
+	pub type Commitments<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Commitment<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+	>;
+
+	/// maps 区块高度 to 在该区块到期的承诺
+	#[pallet::storage]
+	pub type CommitmentQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::Hash, T::MaxCommitmentsPerBlock>,
+		ValueQuery,
 	>;
 
+	/// maps 账户 to 尚未公开的承诺数量
+	#[pallet::storage]
+	#[pallet::getter(fn commitment_count)]
+	pub type CommitmentCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// maps 旧版本存证 to 取代它的新版本
+	#[pallet::storage]
+	#[pallet::getter(fn superseded_by)]
+	pub type SupersededBy<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, ClaimOf<T>>;
+
+	/// maps 新版本存证 to 被它取代的旧版本
+	#[pallet::storage]
+	#[pallet::getter(fn supersedes)]
+	pub type Supersedes<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, ClaimOf<T>>;
+
+	/// maps 存证 to 拥有者设置的锁定, 过期的锁定不会被清除
+	#[pallet::storage]
+	#[pallet::getter(fn claim_lock)]
+	pub type ClaimLocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimOf<T>, ClaimLock<T::BlockNumber>>;
+
+	/// maps 存证 to 尚未裁决的挑战, 裁决前存证不能转移或撤销
+	#[pallet::storage]
+	#[pallet::getter(fn challenges)]
+	pub type Challenges<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimOf<T>,
+		Challenge<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+	>;
+
+	/// maps 代付人 to 代付预算, 预算以锁定余额的形式保管
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_budget)]
+	pub type SponsorBudgets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// maps 账户 to 为其代付 PoE 交易手续费的代付人和剩余次数
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Sponsorship<T::AccountId>>;
+
+	/// 正在执行的代付交易的签名账户和代付人, 只在交易执行期间存在
+	#[pallet::storage]
+	pub type ActiveSponsorship<T: Config> = StorageValue<_, (T::AccountId, T::AccountId)>;
+
+	/// maps 存证 to 从代付预算中支付存证押金的代付人, 没有记录时押金由拥有者锁定
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_payer)]
+	pub type DepositPayers<T: Config> = StorageMap<_, Blake2_128Concat, ClaimOf<T>, T::AccountId>;
+
+	/// maps 存证 to 待接收者接受的转移
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transfers)]
+	pub type PendingTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimOf<T>, PendingTransfer<T::AccountId, T::BlockNumber>>;
+
+	/// maps Merkle 根 to 提交者、叶子数量和锚定时的区块高度
+	#[pallet::storage]
+	#[pallet::getter(fn anchors)]
+	pub type Anchors<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		AnchorInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// 创世时预置的 `(存证, 拥有者)`, 不锁定押金
+		pub claims: Vec<(Vec<u8>, T::AccountId)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { claims: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (claim, owner) in &self.claims {
+				let claim = ClaimOf::<T>::try_from(claim.clone())
+					.expect("genesis claim is longer than MaxClaimLength");
+				assert!(
+					!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim),
+					"duplicate genesis claim {:?}",
+					claim
+				);
+				Pallet::<T>::insert_claim(owner, &claim, Zero::zero())
+					.expect("genesis owner has more than MaxClaimsPerAccount claims");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-		ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
-		ClaimTransferred(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>)
+		/// 创建了存证
+		ClaimCreated { who: T::AccountId, claim: ClaimOf<T>, block: T::BlockNumber },
+		/// 撤销了存证
+		ClaimRevoked { who: T::AccountId, claim: ClaimOf<T> },
+		/// 转移了存证
+		ClaimTransferred { from: T::AccountId, to: T::AccountId, claim: ClaimOf<T> },
+		/// 锚定了 Merkle 根
+		RootAnchored { who: T::AccountId, root: T::Hash, leaf_count: u32 },
+		/// 移除了锚定的 Merkle 根
+		AnchorRemoved { who: T::AccountId, root: T::Hash },
+		/// 存证已过期并被删除
+		ClaimExpired { owner: T::AccountId, claim: ClaimOf<T> },
+		/// 发起了待接收的转移
+		ClaimOffered {
+			from: T::AccountId,
+			to: T::AccountId,
+			claim: ClaimOf<T>,
+			expires_at: Option<T::BlockNumber>,
+		},
+		/// 接收者接受了转移
+		OfferAccepted { who: T::AccountId, claim: ClaimOf<T> },
+		/// 取消了待接收的转移
+		OfferCancelled { from: T::AccountId, claim: ClaimOf<T> },
+		/// 设置了存证元数据
+		MetadataSet { who: T::AccountId, claim: ClaimOf<T> },
+		/// 清除了存证元数据
+		MetadataCleared { who: T::AccountId, claim: ClaimOf<T> },
+		/// 授权了操作员, `claim` 为 `None` 时对拥有者的全部存证有效
+		OperatorApproved { owner: T::AccountId, operator: T::AccountId, claim: Option<ClaimOf<T>> },
+		/// 取消了操作员授权
+		OperatorRevoked { owner: T::AccountId, operator: T::AccountId, claim: Option<ClaimOf<T>> },
+		/// 强制创建了存证
+		ClaimForceCreated { owner: T::AccountId, claim: ClaimOf<T> },
+		/// 强制撤销了存证
+		ClaimForceRevoked { owner: T::AccountId, claim: ClaimOf<T> },
+		/// 强制转移了存证
+		ClaimForceTransferred { from: T::AccountId, to: T::AccountId, claim: ClaimOf<T> },
+		/// 发起了共同签署的存证
+		CosignProposed { creator: T::AccountId, claim: ClaimOf<T>, deadline: T::BlockNumber },
+		/// 签署人签署了存证
+		ClaimCosigned { signer: T::AccountId, claim: ClaimOf<T> },
+		/// 所有签署人都已签署, 存证生效
+		CosignedClaimCreated { creator: T::AccountId, claim: ClaimOf<T>, signers: SignersOf<T> },
+		/// 取消了共同签署的存证
+		CosignCancelled { creator: T::AccountId, claim: ClaimOf<T> },
+		/// 登记了证明人
+		AttesterRegistered { attester: T::AccountId },
+		/// 取消登记了证明人
+		AttesterRemoved { attester: T::AccountId },
+		/// 证明人签名的存证已生效
+		ClaimAttested { attester: T::AccountId, claim: ClaimOf<T> },
+		/// 新版本存证取代了旧版本
+		ClaimSuperseded { who: T::AccountId, old: ClaimOf<T>, new: ClaimOf<T> },
+		/// 提交了存证承诺
+		ClaimCommitted { who: T::AccountId, commitment: T::Hash, expires_at: T::BlockNumber },
+		/// 公开了存证, 创建时间为提交承诺时的区块高度
+		ClaimRevealed { who: T::AccountId, claim: ClaimOf<T>, committed_at: T::BlockNumber },
+		/// 承诺未在期限内公开, 已被清除
+		CommitmentExpired { who: T::AccountId, commitment: T::Hash },
+		/// 承诺之后才创建的同名存证被更早的承诺取代, 押金已退还
+		ClaimPreempted { owner: T::AccountId, claim: ClaimOf<T> },
+		/// 注册了命名空间
+		NamespaceCreated { owner: T::AccountId, namespace: NamespaceId, open: bool },
+		/// 修改了命名空间是否对所有账户开放
+		NamespaceOpenSet { namespace: NamespaceId, open: bool },
+		/// 添加了命名空间的写入者
+		NamespaceWriterAdded { namespace: NamespaceId, writer: T::AccountId },
+		/// 移除了命名空间的写入者
+		NamespaceWriterRemoved { namespace: NamespaceId, writer: T::AccountId },
+		/// 在命名空间中创建了存证
+		NamespacedClaimCreated { who: T::AccountId, namespace: NamespaceId, claim: ClaimOf<T> },
+		/// 撤销了命名空间中的存证
+		NamespacedClaimRevoked { who: T::AccountId, namespace: NamespaceId, claim: ClaimOf<T> },
+		/// 转移了命名空间中的存证
+		NamespacedClaimTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			namespace: NamespaceId,
+			claim: ClaimOf<T>,
+		},
+		/// 锁定了存证, `until` 为 `None` 时永久锁定
+		ClaimLocked { who: T::AccountId, claim: ClaimOf<T>, until: Option<T::BlockNumber> },
+		/// 挑战了存证, 裁决前存证被冻结
+		ClaimChallenged { challenger: T::AccountId, claim: ClaimOf<T> },
+		/// 挑战被驳回, 挑战者的保证金被罚没
+		ChallengeDismissed { challenger: T::AccountId, claim: ClaimOf<T>, slashed: BalanceOf<T> },
+		/// 挑战成立, 拥有者的押金被罚没, 存证已转移给挑战者
+		ChallengeUpheld {
+			challenger: T::AccountId,
+			owner: T::AccountId,
+			claim: ClaimOf<T>,
+			slashed: BalanceOf<T>,
+		},
+		/// 增加了代付预算
+		SponsorshipFunded { sponsor: T::AccountId, amount: BalanceOf<T> },
+		/// 取回了代付预算
+		SponsorshipWithdrawn { sponsor: T::AccountId, amount: BalanceOf<T> },
+		/// 为账户设置了代付次数
+		AccountSponsored { sponsor: T::AccountId, who: T::AccountId, quota: u32 },
+		/// 取消了账户的代付
+		AccountUnsponsored { sponsor: T::AccountId, who: T::AccountId },
+		/// 代付人支付了账户的交易手续费
+		FeeSponsored { sponsor: T::AccountId, who: T::AccountId, fee: BalanceOf<T> },
+		/// 注销了命名空间, 押金已退还
+		NamespaceRemoved { owner: T::AccountId, namespace: NamespaceId },
 	}
 
 	#[pallet::error]
@@ -48,12 +779,82 @@ pub mod pallet {
 		ClaimTooLong,
 		ClaimNotExist,
 		NotClaimOwner,
-		TransferToSelf
+		TransferToSelf,
+		AnchorAlreadyExist,
+		AnchorNotExist,
+		NotAnchorOwner,
+		EmptyAnchor,
+		InvalidExpiry,
+		ExpiryTooFar,
+		TooManyExpiringClaims,
+		TooManyClaims,
+		DirectTransferDisabled,
+		TooManyOperators,
+		OperatorAlreadyApproved,
+		OperatorNotExist,
+		NoSigners,
+		DuplicateSigner,
+		CosignNotExist,
+		NotCosigner,
+		AlreadyCosigned,
+		CosignExpired,
+		NotCosignCreator,
+		AttesterAlreadyRegistered,
+		NotAttester,
+		BadAttestation,
+		StaleAttestation,
+		AlreadySuperseded,
+		CommitmentAlreadyExist,
+		CommitmentNotExist,
+		NotCommitter,
+		TooManyCommitments,
+		TooManyPendingCommitments,
+		NamespaceNotExist,
+		NotNamespaceOwner,
+		NoNamespacePermission,
+		WriterAlreadyAdded,
+		WriterNotExist,
+		TooManyNamespaces,
+		ClaimFrozen,
+		AlreadyChallenged,
+		ChallengeNotExist,
+		ChallengeOwnClaim,
+		ClaimLocked,
+		InvalidLock,
+		InsufficientBudget,
+		AlreadySponsored,
+		NotSponsored,
+		NotSponsor,
+		InvalidQuota,
+		OfferNotExist,
+		NotOfferRecipient,
+		OfferExpired,
+		NamespaceNotEmpty,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			// 每个区块到期的存证数量不超过 MaxExpiringPerBlock
+			let expired = ExpiryQueue::<T>::take(n);
+			let count = expired.len() as u32;
+			for claim in expired {
+				Self::expire_claim(claim);
+			}
+			// 每个区块到期的承诺数量不超过 MaxCommitmentsPerBlock
+			let commitments = CommitmentQueue::<T>::take(n);
+			let commitment_count = commitments.len() as u32;
+			for commitment in commitments {
+				Self::expire_commitment(commitment);
+			}
+			T::WeightInfo::expire_claims(count)
+				.saturating_add(T::WeightInfo::expire_commitments(commitment_count))
+		}
 
+		fn integrity_test() {
+			// 单个账户不能占满一个区块的承诺队列
+			assert!(T::MaxCommitmentsPerAccount::get() < T::MaxCommitmentsPerBlock::get());
+		}
 	}
 
 	#[pallet::call]
@@ -61,54 +862,1577 @@ pub mod pallet {
 
 		///创建存证
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
 		pub fn create_claim(
 			origin: OriginFor<T>,
 			claim: BoundedVec<u8, T::MaxClaimLength>
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
-			Proofs::<T>::insert(
-				&claim,
-				(sender.clone(), frame_system::Pallet::<T>::block_number()),
-			);
-			Self::deposit_event(Event::ClaimCreated(sender, claim));
+			Self::do_create_claim(sender, claim)?;
 			Ok(().into())
 		}
 
 		///吊销存证
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::revoke_claim(claim.len() as u32))]
 		pub fn revoke_claim(
 			origin: OriginFor<T>,
 			claim: BoundedVec<u8, T::MaxClaimLength>
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
-			Proofs::<T>::remove(&claim);
-			Self::deposit_event(Event::ClaimRevoked(sender, claim));
+			Self::do_revoke_claim(sender, claim)?;
 
 			Ok(().into())
 		}
 
 		/// 转移存证
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::transfer_claim(claim.len() as u32))]
 		pub fn transfer_claim(
 			origin: OriginFor<T>,
 			dest: T::AccountId,
 			claim: BoundedVec<u8, T::MaxClaimLength>
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let (owner, block_number) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
-			ensure!(dest != sender, Error::<T>::TransferToSelf);
+			ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+			Self::do_transfer_claim(sender, dest, DEFAULT_NAMESPACE, claim, TransferKind::Direct)?;
+
+			Ok(().into())
+		}
+
+		/// 以文件摘要创建存证, 存证的键为摘要的 SCALE 编码
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::create_claim(DIGEST_CLAIM_LEN))]
+		pub fn create_digest_claim(
+			origin: OriginFor<T>,
+			digest: ClaimDigest,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_claim(sender, Self::digest_claim(&digest)?)?;
+			Ok(().into())
+		}
+
+		/// 批量创建存证, 任意一个失败则全部回滚
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			claims: BoundedVec<ClaimOf<T>, T::MaxClaimsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			for claim in claims {
+				Self::do_create_claim(sender.clone(), claim)?;
+			}
+			Ok(().into())
+		}
+
+		/// 批量吊销存证, 任意一个失败则全部回滚
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32))]
+		pub fn revoke_claims(
+			origin: OriginFor<T>,
+			claims: BoundedVec<ClaimOf<T>, T::MaxClaimsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			for claim in claims {
+				Self::do_revoke_claim(sender.clone(), claim)?;
+			}
+			Ok(().into())
+		}
 
-			Proofs::<T>::set(&claim, Some((dest.clone(), block_number.clone())));
-			Self::deposit_event(Event::ClaimTransferred(sender, dest, claim));
+		/// 批量转移存证, 任意一个失败则全部回滚
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transfer_claims(claims.len() as u32))]
+		pub fn transfer_claims(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			claims: BoundedVec<ClaimOf<T>, T::MaxClaimsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(T::AllowDirectTransfer::get(), Error::<T>::DirectTransferDisabled);
+			for claim in claims {
+				Self::do_transfer_claim(
+					sender.clone(),
+					dest.clone(),
+					DEFAULT_NAMESPACE,
+					claim,
+					TransferKind::Direct,
+				)?;
+			}
+			Ok(().into())
+		}
+
+		/// 创建在 `expires_at` 区块自动删除的存证, 到期时退还押金
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::create_expiring_claim(claim.len() as u32))]
+		pub fn create_expiring_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			expires_at: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at > now, Error::<T>::InvalidExpiry);
+			ensure!(
+				expires_at.saturating_sub(now) <= T::MaxClaimLifetime::get(),
+				Error::<T>::ExpiryTooFar
+			);
+
+			Self::do_create_claim(sender, claim.clone())?;
+			ExpiryQueue::<T>::try_append(expires_at, &claim)
+				.map_err(|_| Error::<T>::TooManyExpiringClaims)?;
+			ClaimExpiry::<T>::insert(&claim, expires_at);
+			Ok(().into())
+		}
+
+		/// 锚定一批文件摘要构成的 Merkle 根
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::anchor_root())]
+		pub fn anchor_root(
+			origin: OriginFor<T>,
+			root: T::Hash,
+			leaf_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(leaf_count > 0, Error::<T>::EmptyAnchor);
+			ensure!(!Anchors::<T>::contains_key(&root), Error::<T>::AnchorAlreadyExist);
+
+			let deposit = Self::claim_deposit(root.as_ref().len());
+			T::Currency::reserve(&sender, deposit)?;
+			Anchors::<T>::insert(
+				&root,
+				AnchorInfo {
+					who: sender.clone(),
+					leaf_count,
+					block: frame_system::Pallet::<T>::block_number(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::RootAnchored { who: sender, root, leaf_count });
+			Ok(().into())
+		}
+
+		/// 移除已锚定的 Merkle 根并退还押金
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::remove_anchor())]
+		pub fn remove_anchor(origin: OriginFor<T>, root: T::Hash) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let anchor = Anchors::<T>::get(&root).ok_or(Error::<T>::AnchorNotExist)?;
+			ensure!(anchor.who == sender, Error::<T>::NotAnchorOwner);
 
+			Anchors::<T>::remove(&root);
+			T::Currency::unreserve(&anchor.who, anchor.deposit);
+			Self::deposit_event(Event::AnchorRemoved { who: sender, root });
 			Ok(().into())
 		}
+
+		/// 向 `dest` 发起存证转移, 需 `dest` 调用 `accept_claim` 接受后才生效,
+		/// 同一存证新的转移会覆盖旧的
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::offer_claim(claim.len() as u32))]
+		pub fn offer_claim(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			claim: ClaimOf<T>,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let owner = Self::owner_of(&claim)?;
+			Self::ensure_can_manage(&sender, &owner, &claim)?;
+			ensure!(dest != owner, Error::<T>::TransferToSelf);
+			if let Some(expires_at) = expires_at {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(expires_at > now, Error::<T>::InvalidExpiry);
+			}
+
+			PendingTransfers::<T>::insert(
+				&claim,
+				PendingTransfer { from: owner.clone(), to: dest.clone(), expires_at },
+			);
+			Self::deposit_event(Event::ClaimOffered { from: owner, to: dest, claim, expires_at });
+			Ok(().into())
+		}
+
+		/// 接受转移给自己的存证, 押金随存证一起转移
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::accept_claim(claim.len() as u32))]
+		pub fn accept_claim(origin: OriginFor<T>, claim: ClaimOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let offer = PendingTransfers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			ensure!(offer.to == sender, Error::<T>::NotOfferRecipient);
+			if let Some(expires_at) = offer.expires_at {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now <= expires_at, Error::<T>::OfferExpired);
+			}
+
+			Self::do_transfer_claim(
+				offer.from,
+				sender.clone(),
+				DEFAULT_NAMESPACE,
+				claim.clone(),
+				TransferKind::Accepted,
+			)?;
+			Self::deposit_event(Event::OfferAccepted { who: sender, claim });
+			Ok(().into())
+		}
+
+		/// 取消尚未被接受的存证转移
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_offer(claim.len() as u32))]
+		pub fn cancel_offer(origin: OriginFor<T>, claim: ClaimOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let offer = PendingTransfers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			Self::ensure_can_manage(&sender, &offer.from, &claim)?;
+
+			PendingTransfers::<T>::remove(&claim);
+			Self::deposit_event(Event::OfferCancelled { from: offer.from, claim });
+			Ok(().into())
+		}
+
+		/// 创建存证并附带元数据
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::create_claim(claim.len() as u32)
+				.saturating_add(T::WeightInfo::set_claim_metadata())
+		)]
+		pub fn create_claim_with_metadata(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			metadata: MetadataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_claim(sender.clone(), claim.clone())?;
+			Self::do_set_metadata(sender, claim, Some(metadata))?;
+			Ok(().into())
+		}
+
+		/// 设置或清除 (`None`) 存证的元数据, 按元数据长度调整押金
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_claim_metadata())]
+		pub fn set_claim_metadata(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			metadata: Option<MetadataOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let owner = Self::owner_of(&claim)?;
+			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			Self::do_set_metadata(sender, claim, metadata)?;
+			Ok(().into())
+		}
+
+		/// 授权 `operator` 代为吊销或转移存证, `claim` 为 `None` 时授权管理全部存证
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::approve_operator())]
+		pub fn approve_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			claim: Option<ClaimOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let add = |operators: &mut OperatorsOf<T>| -> Result<(), Error<T>> {
+				ensure!(!operators.contains(&operator), Error::<T>::OperatorAlreadyApproved);
+				operators.try_push(operator.clone()).map_err(|_| Error::<T>::TooManyOperators)
+			};
+			match &claim {
+				Some(claim) => {
+					let owner = Self::owner_of(claim)?;
+					ensure!(owner == sender, Error::<T>::NotClaimOwner);
+					ClaimOperators::<T>::try_mutate(claim, add)?;
+				},
+				None => Operators::<T>::try_mutate(&sender, add)?,
+			}
+			Self::deposit_event(Event::OperatorApproved { owner: sender, operator, claim });
+			Ok(().into())
+		}
+
+		/// 取消对 `operator` 的授权
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::revoke_operator())]
+		pub fn revoke_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			claim: Option<ClaimOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let remove = |operators: &mut OperatorsOf<T>| -> Result<(), Error<T>> {
+				let len = operators.len();
+				operators.retain(|approved| approved != &operator);
+				ensure!(operators.len() < len, Error::<T>::OperatorNotExist);
+				Ok(())
+			};
+			match &claim {
+				Some(claim) => {
+					let owner = Self::owner_of(claim)?;
+					ensure!(owner == sender, Error::<T>::NotClaimOwner);
+					ClaimOperators::<T>::try_mutate(claim, remove)?;
+				},
+				None => Operators::<T>::try_mutate(&sender, remove)?,
+			}
+			Self::deposit_event(Event::OperatorRevoked { owner: sender, operator, claim });
+			Ok(().into())
+		}
+
+		/// 以 `owner` 的名义强制创建存证, 不锁定押金
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
+		pub fn force_create_claim(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(
+				!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			Self::clear_expired_cosign(&claim)?;
+			Self::insert_claim(&owner, &claim, Zero::zero())?;
+			Self::deposit_event(Event::ClaimForceCreated { owner, claim });
+			Ok(().into())
+		}
+
+		/// 强制吊销存证, 押金退还给拥有者
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::revoke_claim(claim.len() as u32))]
+		pub fn force_revoke_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner =
+				Self::remove_claim(DEFAULT_NAMESPACE, &claim).ok_or(Error::<T>::ClaimNotExist)?;
+			Self::deposit_event(Event::ClaimForceRevoked { owner, claim });
+			Ok(().into())
+		}
+
+		/// 强制将存证转移给 `dest`, 押金随存证一起转移
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::transfer_claim(claim.len() as u32))]
+		pub fn force_transfer_claim(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = Self::owner_of(&claim)?;
+			Self::do_transfer_claim(owner, dest, DEFAULT_NAMESPACE, claim, TransferKind::Forced)?;
+			Ok(().into())
+		}
+
+		/// 发起需要 `signers` 全部在 `deadline` 之前签署才生效的存证
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::propose_cosigned_claim())]
+		pub fn propose_cosigned_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			signers: SignersOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			Self::clear_expired_cosign(&claim)?;
+			ensure!(!signers.is_empty(), Error::<T>::NoSigners);
+			let mut sorted = signers.clone().into_inner();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(sorted.len() == signers.len(), Error::<T>::DuplicateSigner);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				deadline > now && deadline <= now.saturating_add(T::MaxCosignPeriod::get()),
+				Error::<T>::InvalidExpiry
+			);
+
+			// 押金同时覆盖签署人列表占用的存储
+			let deposit = Self::claim_deposit(claim.len() + signers.encoded_size());
+			T::Currency::reserve(&sender, deposit)?;
+			PendingCosigns::<T>::insert(
+				&claim,
+				CosignProposal {
+					creator: sender.clone(),
+					signers,
+					signed: Default::default(),
+					deadline,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::CosignProposed { creator: sender, claim, deadline });
+			Ok(().into())
+		}
+
+		/// 签署共同签署存证, 最后一个签署人签署后存证生效
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::cosign_claim())]
+		pub fn cosign_claim(origin: OriginFor<T>, claim: ClaimOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let mut proposal =
+				PendingCosigns::<T>::get(&claim).ok_or(Error::<T>::CosignNotExist)?;
+			ensure!(proposal.signers.contains(&sender), Error::<T>::NotCosigner);
+			ensure!(!proposal.signed.contains(&sender), Error::<T>::AlreadyCosigned);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= proposal.deadline, Error::<T>::CosignExpired);
+
+			proposal
+				.signed
+				.try_push(sender.clone())
+				.expect("signed is a subset of signers, which is bounded by MaxSigners; qed");
+			Self::deposit_event(Event::ClaimCosigned { signer: sender, claim: claim.clone() });
+
+			if proposal.signed.len() < proposal.signers.len() {
+				PendingCosigns::<T>::insert(&claim, proposal);
+				return Ok(().into())
+			}
+
+			PendingCosigns::<T>::remove(&claim);
+			ensure!(
+				!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			Self::insert_claim(&proposal.creator, &claim, proposal.deposit)?;
+			ClaimSigners::<T>::insert(&claim, &proposal.signers);
+			Self::deposit_event(Event::CosignedClaimCreated {
+				creator: proposal.creator,
+				claim,
+				signers: proposal.signers,
+			});
+			Ok(().into())
+		}
+
+		/// 发起者取消尚未生效的共同签署存证并取回押金; 签署期限过后任何人都可以清除,
+		/// 押金退还给发起者
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::cancel_cosigned_claim())]
+		pub fn cancel_cosigned_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let proposal = PendingCosigns::<T>::get(&claim).ok_or(Error::<T>::CosignNotExist)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				proposal.creator == sender || now > proposal.deadline,
+				Error::<T>::NotCosignCreator
+			);
+
+			PendingCosigns::<T>::remove(&claim);
+			T::Currency::unreserve(&proposal.creator, proposal.deposit);
+			Self::deposit_event(Event::CosignCancelled { creator: proposal.creator, claim });
+			Ok(().into())
+		}
+
+		/// 提交证明人签名的存证, 由中继以无签名交易提交, 存证归证明人所有且不锁定押金
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::create_claim_attested(payload.claim.len() as u32))]
+		pub fn create_claim_attested(
+			origin: OriginFor<T>,
+			payload: AttestationPayload<T::AccountId, ClaimOf<T>>,
+			signature: T::AttesterSignature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			ensure!(Attesters::<T>::contains_key(&payload.attester), Error::<T>::NotAttester);
+			ensure!(Self::verify_attestation(&payload, &signature), Error::<T>::BadAttestation);
+			let nonce = AttesterNonces::<T>::get(&payload.attester);
+			ensure!(payload.nonce == nonce, Error::<T>::StaleAttestation);
+			ensure!(
+				!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &payload.claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			Self::clear_expired_cosign(&payload.claim)?;
+
+			AttesterNonces::<T>::insert(&payload.attester, nonce.saturating_add(1));
+			Self::insert_claim(&payload.attester, &payload.claim, Zero::zero())?;
+			Self::deposit_event(Event::ClaimAttested {
+				attester: payload.attester,
+				claim: payload.claim,
+			});
+			Ok(().into())
+		}
+
+		/// 登记证明人
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::register_attester())]
+		pub fn register_attester(
+			origin: OriginFor<T>,
+			attester: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(
+				!Attesters::<T>::contains_key(&attester),
+				Error::<T>::AttesterAlreadyRegistered
+			);
+			Attesters::<T>::insert(&attester, ());
+			Self::deposit_event(Event::AttesterRegistered { attester });
+			Ok(().into())
+		}
+
+		/// 取消登记证明人, 其已提交的存证不受影响
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::remove_attester())]
+		pub fn remove_attester(
+			origin: OriginFor<T>,
+			attester: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Attesters::<T>::contains_key(&attester), Error::<T>::NotAttester);
+			Attesters::<T>::remove(&attester);
+			Self::deposit_event(Event::AttesterRemoved { attester });
+			Ok(().into())
+		}
+
+		/// 以新版本存证 `new` 取代 `old`, 旧版本保留但标记为已被取代
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::supersede_claim(new.len() as u32))]
+		pub fn supersede_claim(
+			origin: OriginFor<T>,
+			old: ClaimOf<T>,
+			new: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let owner = Self::owner_of(&old)?;
+			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			ensure!(!SupersededBy::<T>::contains_key(&old), Error::<T>::AlreadySuperseded);
+
+			Self::do_create_claim(sender.clone(), new.clone())?;
+			SupersededBy::<T>::insert(&old, &new);
+			Supersedes::<T>::insert(&new, &old);
+			Self::deposit_event(Event::ClaimSuperseded { who: sender, old, new });
+			Ok(().into())
+		}
+
+		/// 提交存证承诺 `hash(claim ‖ salt)`, 在 `RevealPeriod` 内公开后存证的创建时间为当前区块
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::commit_claim())]
+		pub fn commit_claim(
+			origin: OriginFor<T>,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!Commitments::<T>::contains_key(&commitment),
+				Error::<T>::CommitmentAlreadyExist
+			);
+			CommitmentCount::<T>::try_mutate(&sender, |count| -> DispatchResult {
+				ensure!(
+					*count < T::MaxCommitmentsPerAccount::get(),
+					Error::<T>::TooManyPendingCommitments
+				);
+				*count += 1;
+				Ok(())
+			})?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(T::RevealPeriod::get());
+			CommitmentQueue::<T>::try_append(expires_at, commitment)
+				.map_err(|_| Error::<T>::TooManyCommitments)?;
+			let deposit = T::CommitmentDeposit::get();
+			T::Currency::reserve(&sender, deposit)?;
+			Commitments::<T>::insert(
+				&commitment,
+				Commitment { who: sender.clone(), committed_at: now, expires_at, deposit },
+			);
+			Self::deposit_event(Event::ClaimCommitted { who: sender, commitment, expires_at });
+			Ok(().into())
+		}
+
+		/// 公开已承诺的存证, 退还承诺押金并锁定存证押金
+		///
+		/// 承诺之后他人抢先创建的同名存证或发起的共同签署会被取消, 并退还其押金;
+		/// 已锁定或受到挑战的存证不会被取消
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::reveal_claim(claim.len() as u32))]
+		pub fn reveal_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			salt: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let commitment = Self::commitment_of(&claim, &salt);
+			let info = Commitments::<T>::get(&commitment).ok_or(Error::<T>::CommitmentNotExist)?;
+			ensure!(info.who == sender, Error::<T>::NotCommitter);
+			if let Some(existing) = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim) {
+				// 承诺之前已存在的存证不受影响
+				ensure!(existing.created_at > info.committed_at, Error::<T>::ProofAlreadyExist);
+				// 锁定或受到挑战的存证由拥有者或裁决决定去留, 不会被取代
+				ensure!(!Challenges::<T>::contains_key(&claim), Error::<T>::ClaimFrozen);
+				ensure!(!Self::is_locked(&claim), Error::<T>::ClaimLocked);
+				Self::remove_claim(DEFAULT_NAMESPACE, &claim);
+				Self::deposit_event(Event::ClaimPreempted {
+					owner: existing.owner,
+					claim: claim.clone(),
+				});
+			}
+			if let Some(proposal) = PendingCosigns::<T>::take(&claim) {
+				T::Currency::unreserve(&proposal.creator, proposal.deposit);
+				Self::deposit_event(Event::CosignCancelled {
+					creator: proposal.creator,
+					claim: claim.clone(),
+				});
+			}
+
+			Commitments::<T>::remove(&commitment);
+			Self::decrease_commitment_count(&sender);
+			CommitmentQueue::<T>::mutate_exists(info.expires_at, |queue| {
+				if let Some(commitments) = queue {
+					commitments.retain(|queued| queued != &commitment);
+					if commitments.is_empty() {
+						*queue = None;
+					}
+				}
+			});
+			T::Currency::unreserve(&sender, info.deposit);
+			let deposit = Self::claim_deposit(claim.len());
+			Self::reserve_claim_deposit(&sender, &claim, deposit)?;
+			Self::insert_claim_at(&sender, DEFAULT_NAMESPACE, &claim, deposit, info.committed_at)?;
+			Self::deposit_event(Event::ClaimRevealed {
+				who: sender,
+				claim,
+				committed_at: info.committed_at,
+			});
+			Ok(().into())
+		}
+
+		/// 注册新的命名空间并锁定押金, 编号按注册顺序分配
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::create_namespace())]
+		pub fn create_namespace(origin: OriginFor<T>, open: bool) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let namespace = NextNamespaceId::<T>::get();
+			let next = namespace.checked_add(1).ok_or(Error::<T>::TooManyNamespaces)?;
+
+			let deposit = T::NamespaceDeposit::get();
+			T::Currency::reserve(&sender, deposit)?;
+			Namespaces::<T>::insert(
+				namespace,
+				NamespaceInfo { owner: sender.clone(), open, deposit },
+			);
+			NextNamespaceId::<T>::put(next);
+			Self::deposit_event(Event::NamespaceCreated { owner: sender, namespace, open });
+			Ok(().into())
+		}
+
+		/// 设置命名空间是否对所有账户开放
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_namespace_open())]
+		pub fn set_namespace_open(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			open: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Namespaces::<T>::try_mutate(namespace, |info| -> DispatchResult {
+				let info = info.as_mut().ok_or(Error::<T>::NamespaceNotExist)?;
+				ensure!(info.owner == sender, Error::<T>::NotNamespaceOwner);
+				info.open = open;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::NamespaceOpenSet { namespace, open });
+			Ok(().into())
+		}
+
+		/// 允许 `writer` 在命名空间中创建存证
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::add_namespace_writer())]
+		pub fn add_namespace_writer(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			writer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_namespace_owner(&sender, namespace)?;
+			ensure!(
+				!NamespaceWriters::<T>::contains_key(namespace, &writer),
+				Error::<T>::WriterAlreadyAdded
+			);
+
+			NamespaceWriters::<T>::insert(namespace, &writer, ());
+			Self::deposit_event(Event::NamespaceWriterAdded { namespace, writer });
+			Ok(().into())
+		}
+
+		/// 移除命名空间的写入者, 已创建的存证不受影响
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::remove_namespace_writer())]
+		pub fn remove_namespace_writer(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			writer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_namespace_owner(&sender, namespace)?;
+			ensure!(
+				NamespaceWriters::<T>::contains_key(namespace, &writer),
+				Error::<T>::WriterNotExist
+			);
+
+			NamespaceWriters::<T>::remove(namespace, &writer);
+			Self::deposit_event(Event::NamespaceWriterRemoved { namespace, writer });
+			Ok(().into())
+		}
+
+		/// 在已注册的命名空间中创建存证, 不同命名空间中的相同存证互不冲突
+		///
+		/// 存证计入 `NamespacedClaimCount`, 不出现在 `claims_of` 中.
+		/// 附加功能的调用只查找默认命名空间, 对其他命名空间中的存证返回 `ClaimNotExist`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::create_namespaced_claim(claim.len() as u32))]
+		pub fn create_namespaced_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let info = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(
+				info.open ||
+					info.owner == sender ||
+					NamespaceWriters::<T>::contains_key(namespace, &sender),
+				Error::<T>::NoNamespacePermission
+			);
+			ensure!(!Proofs::<T>::contains_key(namespace, &claim), Error::<T>::ProofAlreadyExist);
+
+			let deposit = Self::claim_deposit(claim.len());
+			T::Currency::reserve(&sender, deposit)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::insert_claim_at(&sender, namespace, &claim, deposit, now)?;
+			Self::deposit_event(Event::NamespacedClaimCreated { who: sender, namespace, claim });
+			Ok(().into())
+		}
+
+		/// 撤销命名空间中的存证并退还押金
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::revoke_namespaced_claim(claim.len() as u32))]
+		pub fn revoke_namespaced_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(namespace != DEFAULT_NAMESPACE, Error::<T>::NamespaceNotExist);
+			let info = Proofs::<T>::get(namespace, &claim).ok_or(Error::<T>::ClaimNotExist)?;
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			Self::remove_claim(namespace, &claim);
+			Self::deposit_event(Event::NamespacedClaimRevoked { who: sender, namespace, claim });
+			Ok(().into())
+		}
+
+		/// 转移命名空间中的存证, 押金随存证转移给新的拥有者
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::transfer_namespaced_claim(claim.len() as u32))]
+		pub fn transfer_namespaced_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			dest: T::AccountId,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(namespace != DEFAULT_NAMESPACE, Error::<T>::NamespaceNotExist);
+			Self::do_transfer_claim(sender, dest, namespace, claim, TransferKind::Direct)?;
+			Ok(().into())
+		}
+
+		/// 注销没有存证和写入者的命名空间并退还押金, 编号不会被重新分配
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::remove_namespace())]
+		pub fn remove_namespace(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let info = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(info.owner == sender, Error::<T>::NotNamespaceOwner);
+			// 写入者需要先逐个移除, 避免在一次调用中清理无界的存储
+			ensure!(
+				Proofs::<T>::iter_key_prefix(namespace).next().is_none() &&
+					NamespaceWriters::<T>::iter_key_prefix(namespace).next().is_none(),
+				Error::<T>::NamespaceNotEmpty
+			);
+
+			Namespaces::<T>::remove(namespace);
+			T::Currency::unreserve(&sender, info.deposit);
+			Self::deposit_event(Event::NamespaceRemoved { owner: sender, namespace });
+			Ok(().into())
+		}
+
+		/// 锁定存证, 锁定期间拥有者和操作员都不能撤销或转移; `until` 为 `None` 时永久锁定.
+		/// 锁定只能延长不能缩短, 到期的存证仍会被删除
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::lock_claim(claim.len() as u32))]
+		pub fn lock_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			until: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let owner = Self::owner_of(&claim)?;
+			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(until.map_or(true, |until| until > now), Error::<T>::InvalidLock);
+			let current = ClaimLocks::<T>::get(&claim).filter(|lock| lock.is_active(&now));
+			if let Some(current) = current {
+				let extends = match (current, until) {
+					(ClaimLock::Until(current), Some(until)) => until > current,
+					(ClaimLock::Until(_), None) => true,
+					(ClaimLock::Forever, _) => false,
+				};
+				ensure!(extends, Error::<T>::ClaimLocked);
+			}
+
+			let lock = until.map_or(ClaimLock::Forever, ClaimLock::Until);
+			ClaimLocks::<T>::insert(&claim, lock);
+			Self::deposit_event(Event::ClaimLocked { who: sender, claim, until });
+			Ok(().into())
+		}
+
+		/// 挑战他人的存证并锁定保证金, 裁决前存证不能转移或撤销
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::challenge_claim(claim.len() as u32))]
+		pub fn challenge_claim(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let owner = Self::owner_of(&claim)?;
+			ensure!(owner != sender, Error::<T>::ChallengeOwnClaim);
+			ensure!(!Challenges::<T>::contains_key(&claim), Error::<T>::AlreadyChallenged);
+
+			let bond = T::ChallengeBond::get();
+			T::Currency::reserve(&sender, bond)?;
+			Challenges::<T>::insert(
+				&claim,
+				Challenge {
+					challenger: sender.clone(),
+					created_at: frame_system::Pallet::<T>::block_number(),
+					bond,
+				},
+			);
+			Self::deposit_event(Event::ClaimChallenged { challenger: sender, claim });
+			Ok(().into())
+		}
+
+		/// 裁决存证挑战: 驳回时罚没挑战者的保证金;
+		/// 成立时罚没拥有者的存证押金, 退还保证金并将存证转移给挑战者.
+		/// 挑战期间已到期的存证在裁决后立即过期
+		#[pallet::call_index(37)]
+		#[pallet::weight(
+			T::WeightInfo::resolve_challenge(claim.len() as u32)
+				.saturating_add(T::WeightInfo::expire_claims(1))
+		)]
+		pub fn resolve_challenge(
+			origin: OriginFor<T>,
+			claim: ClaimOf<T>,
+			verdict: ChallengeVerdict,
+		) -> DispatchResultWithPostInfo {
+			T::ArbiterOrigin::ensure_origin(origin)?;
+			let challenge = Challenges::<T>::take(&claim).ok_or(Error::<T>::ChallengeNotExist)?;
+			let challenger = challenge.challenger;
+			match verdict {
+				ChallengeVerdict::Dismissed => {
+					let slashed = Self::slash_reserved(&challenger, challenge.bond);
+					Self::deposit_event(Event::ChallengeDismissed {
+						challenger,
+						claim: claim.clone(),
+						slashed,
+					});
+				},
+				ChallengeVerdict::Upheld => {
+					let mut info = Proofs::<T>::get(DEFAULT_NAMESPACE, &claim)
+						.ok_or(Error::<T>::ClaimNotExist)?;
+					let owner = info.owner.clone();
+					let payer = DepositPayers::<T>::take(&claim).unwrap_or_else(|| owner.clone());
+					let slashed = Self::slash_reserved(&payer, info.deposit);
+					// 存证押金已被罚没, 只有元数据押金随存证转移
+					info.deposit = Zero::zero();
+					Proofs::<T>::insert(DEFAULT_NAMESPACE, &claim, info);
+					T::Currency::unreserve(&challenger, challenge.bond);
+					Self::do_transfer_claim(
+						owner.clone(),
+						challenger.clone(),
+						DEFAULT_NAMESPACE,
+						claim.clone(),
+						TransferKind::Disputed,
+					)?;
+					Self::deposit_event(Event::ChallengeUpheld {
+						challenger,
+						owner,
+						claim: claim.clone(),
+						slashed,
+					});
+				},
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			if ClaimExpiry::<T>::get(&claim).map_or(false, |expires_at| expires_at <= now) {
+				Self::expire_claim(claim);
+			}
+			Ok(().into())
+		}
+
+		/// 锁定 `amount` 作为代付预算, 用于支付被代付账户的 PoE 交易手续费
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::fund_sponsorship())]
+		pub fn fund_sponsorship(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			T::Currency::reserve(&sender, amount)?;
+			SponsorBudgets::<T>::mutate(&sender, |budget| *budget = budget.saturating_add(amount));
+			Self::deposit_event(Event::SponsorshipFunded { sponsor: sender, amount });
+			Ok(().into())
+		}
+
+		/// 取回尚未使用的代付预算
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::withdraw_sponsorship())]
+		pub fn withdraw_sponsorship(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			SponsorBudgets::<T>::try_mutate_exists(&sender, |budget| -> DispatchResult {
+				let remaining = budget
+					.unwrap_or_else(Zero::zero)
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::InsufficientBudget)?;
+				*budget = Some(remaining).filter(|remaining| !remaining.is_zero());
+				Ok(())
+			})?;
+			T::Currency::unreserve(&sender, amount);
+			Self::deposit_event(Event::SponsorshipWithdrawn { sponsor: sender, amount });
+			Ok(().into())
+		}
+
+		/// 为 `who` 代付 `quota` 笔 PoE 交易的手续费, 已代付的账户可以重新设置次数.
+		/// 代付交易中创建存证的押金也从预算中支付, 存证删除时退回预算
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::sponsor_account())]
+		pub fn sponsor_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			quota: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(quota > 0, Error::<T>::InvalidQuota);
+			Sponsorships::<T>::try_mutate(&who, |sponsorship| -> DispatchResult {
+				if let Some(sponsorship) = sponsorship {
+					ensure!(sponsorship.sponsor == sender, Error::<T>::AlreadySponsored);
+				}
+				*sponsorship = Some(Sponsorship { sponsor: sender.clone(), remaining: quota });
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AccountSponsored { sponsor: sender, who, quota });
+			Ok(().into())
+		}
+
+		/// 取消对 `who` 的代付, 代付人和被代付账户都可以取消
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::unsponsor_account())]
+		pub fn unsponsor_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let sponsorship = Sponsorships::<T>::get(&who).ok_or(Error::<T>::NotSponsored)?;
+			ensure!(sender == sponsorship.sponsor || sender == who, Error::<T>::NotSponsor);
+			Sponsorships::<T>::remove(&who);
+			Self::deposit_event(Event::AccountUnsponsored { sponsor: sponsorship.sponsor, who });
+			Ok(().into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::create_claim_attested { payload, signature } =>
+					Self::validate_attestation(payload, signature),
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// 摘要存证对应的存证键
+		pub fn digest_claim(digest: &ClaimDigest) -> Result<ClaimOf<T>, Error<T>> {
+			BoundedVec::try_from(digest.encode()).map_err(|_| Error::<T>::ClaimTooLong)
+		}
+
+		/// 存证是否有尚未过期的共同签署提案
+		fn has_pending_cosign(claim: &ClaimOf<T>) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			PendingCosigns::<T>::get(claim).map_or(false, |proposal| now <= proposal.deadline)
+		}
+
+		/// 清除已过期的共同签署提案并退还发起者的押金, 提案尚未过期时存证已被占用
+		fn clear_expired_cosign(claim: &ClaimOf<T>) -> DispatchResult {
+			ensure!(!Self::has_pending_cosign(claim), Error::<T>::ProofAlreadyExist);
+			if let Some(proposal) = PendingCosigns::<T>::take(claim) {
+				T::Currency::unreserve(&proposal.creator, proposal.deposit);
+				Self::deposit_event(Event::CosignCancelled {
+					creator: proposal.creator,
+					claim: claim.clone(),
+				});
+			}
+			Ok(())
+		}
+
+		fn do_create_claim(sender: T::AccountId, claim: ClaimOf<T>) -> DispatchResult {
+			ensure!(
+				!Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			Self::clear_expired_cosign(&claim)?;
+			let deposit = Self::claim_deposit(claim.len());
+			Self::reserve_claim_deposit(&sender, &claim, deposit)?;
+			Self::insert_claim(&sender, &claim, deposit)?;
+			let block = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::ClaimCreated { who: sender, claim, block });
+			Ok(())
+		}
+
+		fn insert_claim(
+			owner: &T::AccountId,
+			claim: &ClaimOf<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::insert_claim_at(owner, DEFAULT_NAMESPACE, claim, deposit, now)
+		}
+
+		/// 默认命名空间中存证的拥有者
+		fn owner_of(claim: &ClaimOf<T>) -> Result<T::AccountId, Error<T>> {
+			Proofs::<T>::get(DEFAULT_NAMESPACE, claim)
+				.map(|info| info.owner)
+				.ok_or(Error::<T>::ClaimNotExist)
+		}
+
+		/// 以 `created_at` 作为创建时间写入存证
+		fn insert_claim_at(
+			owner: &T::AccountId,
+			namespace: NamespaceId,
+			claim: &ClaimOf<T>,
+			deposit: BalanceOf<T>,
+			created_at: T::BlockNumber,
+		) -> DispatchResult {
+			Self::add_owned_claim(owner, namespace, claim)?;
+			Proofs::<T>::insert(
+				namespace,
+				claim,
+				ClaimInfo { owner: owner.clone(), created_at, updated_at: created_at, deposit },
+			);
+			Ok(())
+		}
+
+		fn do_revoke_claim(sender: T::AccountId, claim: ClaimOf<T>) -> DispatchResult {
+			let owner = Self::owner_of(&claim)?;
+			Self::ensure_can_manage(&sender, &owner, &claim)?;
+			ensure!(!Challenges::<T>::contains_key(&claim), Error::<T>::ClaimFrozen);
+			ensure!(!Self::is_locked(&claim), Error::<T>::ClaimLocked);
+			Self::remove_claim(DEFAULT_NAMESPACE, &claim);
+			Self::deposit_event(Event::ClaimRevoked { who: owner, claim });
+			Ok(())
+		}
+
+		/// 删除存证及其附属数据并退还押金, 返回原拥有者
+		fn remove_claim(namespace: NamespaceId, claim: &ClaimOf<T>) -> Option<T::AccountId> {
+			let ClaimInfo { owner, deposit, .. } = Proofs::<T>::take(namespace, claim)?;
+			Self::remove_owned_claim(&owner, namespace, claim);
+			if namespace != DEFAULT_NAMESPACE {
+				// 其他命名空间中的存证没有附加数据
+				T::Currency::unreserve(&owner, deposit);
+				return Some(owner)
+			}
+			Self::cancel_expiry(claim);
+			PendingTransfers::<T>::remove(claim);
+			ClaimHistory::<T>::remove(claim);
+			ClaimOperators::<T>::remove(claim);
+			ClaimSigners::<T>::remove(claim);
+			Self::unlink_version(claim);
+			ClaimLocks::<T>::remove(claim);
+			// 存证被强制撤销或过期后挑战失去意义, 退还保证金
+			if let Some(challenge) = Challenges::<T>::take(claim) {
+				T::Currency::unreserve(&challenge.challenger, challenge.bond);
+			}
+			if let Some(sponsor) = DepositPayers::<T>::take(claim) {
+				// 代付人的押金一直锁定在其账户中, 直接退回预算
+				SponsorBudgets::<T>::mutate(&sponsor, |budget| {
+					*budget = budget.saturating_add(deposit)
+				});
+			} else {
+				T::Currency::unreserve(&owner, deposit);
+			}
+			T::Currency::unreserve(&owner, Self::remove_metadata(claim));
+			Some(owner)
+		}
+
+		/// 从版本链中摘除存证: 前后版本直接相连, 删除最新版本后前一个版本重新成为最新版本
+		fn unlink_version(claim: &ClaimOf<T>) {
+			match (Supersedes::<T>::take(claim), SupersededBy::<T>::take(claim)) {
+				(Some(prev), Some(next)) => {
+					SupersededBy::<T>::insert(&prev, &next);
+					Supersedes::<T>::insert(&next, &prev);
+				},
+				(Some(prev), None) => SupersededBy::<T>::remove(prev),
+				(None, Some(next)) => Supersedes::<T>::remove(next),
+				(None, None) => {},
+			}
+		}
+
+		fn cancel_expiry(claim: &ClaimOf<T>) {
+			if let Some(expires_at) = ClaimExpiry::<T>::take(claim) {
+				ExpiryQueue::<T>::mutate_exists(expires_at, |queue| {
+					if let Some(claims) = queue {
+						claims.retain(|queued| queued != claim);
+						if claims.is_empty() {
+							*queue = None;
+						}
+					}
+				});
+			}
+		}
+
+		fn expire_claim(claim: ClaimOf<T>) {
+			// 受到挑战的存证推迟到裁决之后再到期, 到期时间保留在 ClaimExpiry 中
+			if Challenges::<T>::contains_key(&claim) {
+				return
+			}
+			// 到期队列已在 on_initialize 中整体取出, 无需再从队列中移除
+			ClaimExpiry::<T>::remove(&claim);
+			if let Some(owner) = Self::remove_claim(DEFAULT_NAMESPACE, &claim) {
+				Self::deposit_event(Event::ClaimExpired { owner, claim });
+			}
+		}
+
+		fn expire_commitment(commitment: T::Hash) {
+			// 承诺队列已在 on_initialize 中整体取出, 无需再从队列中移除
+			if let Some(info) = Commitments::<T>::take(&commitment) {
+				Self::decrease_commitment_count(&info.who);
+				T::Currency::unreserve(&info.who, info.deposit);
+				Self::deposit_event(Event::CommitmentExpired { who: info.who, commitment });
+			}
+		}
+
+		fn do_transfer_claim(
+			sender: T::AccountId,
+			dest: T::AccountId,
+			namespace: NamespaceId,
+			claim: ClaimOf<T>,
+			kind: TransferKind,
+		) -> DispatchResult {
+			let mut info = Proofs::<T>::get(namespace, &claim).ok_or(Error::<T>::ClaimNotExist)?;
+			let owner = info.owner.clone();
+			let is_default = namespace == DEFAULT_NAMESPACE;
+			if is_default {
+				Self::ensure_can_manage(&sender, &owner, &claim)?;
+			} else {
+				ensure!(sender == owner, Error::<T>::NotClaimOwner);
+			}
+			ensure!(dest != owner, Error::<T>::TransferToSelf);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut total = info.deposit;
+			if is_default {
+				ensure!(!Challenges::<T>::contains_key(&claim), Error::<T>::ClaimFrozen);
+				// 锁定只约束拥有者和操作员, 强制转移和挑战裁决不受影响
+				if matches!(kind, TransferKind::Direct | TransferKind::Accepted) {
+					ensure!(!Self::is_locked(&claim), Error::<T>::ClaimLocked);
+				}
+
+				let record = OwnershipRecord { owner: owner.clone(), block: now, kind };
+				ClaimHistory::<T>::mutate(&claim, |history| {
+					// 记录已满时丢弃最早的一条, 转移不会因历史记录而失败
+					if !history.is_empty() && history.len() as u32 >= T::MaxHistoryLength::get() {
+						history.remove(0);
+					}
+					let _ = history.try_push(record);
+				});
+				// 代付人支付的存证押金留在代付人处, 不随存证转移
+				if DepositPayers::<T>::contains_key(&claim) {
+					total = Zero::zero();
+				}
+				// 元数据的押金随存证一起转移给新的拥有者
+				let metadata_deposit = ClaimMetadataOf::<T>::get(&claim)
+					.map_or_else(Zero::zero, |(_, deposit)| deposit);
+				total = total.saturating_add(metadata_deposit);
+				PendingTransfers::<T>::remove(&claim);
+				ClaimOperators::<T>::remove(&claim);
+			}
+
+			if !total.is_zero() {
+				T::Currency::repatriate_reserved(&owner, &dest, total, BalanceStatus::Reserved)?;
+			}
+			Self::remove_owned_claim(&owner, namespace, &claim);
+			Self::add_owned_claim(&dest, namespace, &claim)?;
+			info.owner = dest.clone();
+			info.updated_at = now;
+			Proofs::<T>::insert(namespace, &claim, info);
+			let event = if !is_default {
+				Event::NamespacedClaimTransferred { from: owner, to: dest, namespace, claim }
+			} else if matches!(kind, TransferKind::Forced) {
+				Event::ClaimForceTransferred { from: owner, to: dest, claim }
+			} else {
+				Event::ClaimTransferred { from: owner, to: dest, claim }
+			};
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		fn do_set_metadata(
+			owner: T::AccountId,
+			claim: ClaimOf<T>,
+			metadata: Option<MetadataOf<T>>,
+		) -> DispatchResult {
+			let old_deposit =
+				ClaimMetadataOf::<T>::get(&claim).map_or_else(Zero::zero, |(_, deposit)| deposit);
+			let new_deposit = metadata.as_ref().map_or_else(Zero::zero, Self::metadata_deposit);
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&owner, new_deposit - old_deposit)?;
+			} else {
+				T::Currency::unreserve(&owner, old_deposit - new_deposit);
+			}
+
+			match metadata {
+				Some(metadata) => {
+					ClaimMetadataOf::<T>::insert(&claim, (metadata, new_deposit));
+					Self::deposit_event(Event::MetadataSet { who: owner, claim });
+				},
+				None => {
+					ClaimMetadataOf::<T>::remove(&claim);
+					Self::deposit_event(Event::MetadataCleared { who: owner, claim });
+				},
+			}
+			Ok(())
+		}
+
+		/// 存证当前是否处于锁定期
+		fn is_locked(claim: &ClaimOf<T>) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			ClaimLocks::<T>::get(claim).map_or(false, |lock| lock.is_active(&now))
+		}
+
+		/// 罚没 `who` 锁定的 `amount`, 返回实际罚没的数额
+		fn slash_reserved(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let (imbalance, _) = T::Currency::slash_reserved(who, amount);
+			let slashed = imbalance.peek();
+			T::Slashed::on_unbalanced(imbalance);
+			slashed
+		}
+
+		/// 删除存证的元数据, 返回需要退还的押金
+		fn remove_metadata(claim: &ClaimOf<T>) -> BalanceOf<T> {
+			ClaimMetadataOf::<T>::take(claim).map_or_else(Zero::zero, |(_, deposit)| deposit)
+		}
+
+		/// `who` 是否为 `owner` 本人或其授权的操作员
+		fn ensure_can_manage(
+			who: &T::AccountId,
+			owner: &T::AccountId,
+			claim: &ClaimOf<T>,
+		) -> DispatchResult {
+			ensure!(
+				who == owner ||
+					Operators::<T>::get(owner).contains(who) ||
+					ClaimOperators::<T>::get(claim).contains(who),
+				Error::<T>::NotClaimOwner
+			);
+			Ok(())
+		}
+
+		/// 默认命名空间中的存证计入 `ClaimCount` 和 `ClaimsByOwner`, 其他命名空间中的存证只计入
+		/// `NamespacedClaimCount`
+		fn add_owned_claim(
+			who: &T::AccountId,
+			namespace: NamespaceId,
+			claim: &ClaimOf<T>,
+		) -> DispatchResult {
+			let increase = |count: &mut u32| -> DispatchResult {
+				ensure!(*count < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+				*count += 1;
+				Ok(())
+			};
+			if namespace == DEFAULT_NAMESPACE {
+				ClaimCount::<T>::try_mutate(who, increase)?;
+				ClaimsByOwner::<T>::insert(who, claim, ());
+			} else {
+				NamespacedClaimCount::<T>::try_mutate(who, increase)?;
+			}
+			Ok(())
+		}
+
+		fn remove_owned_claim(who: &T::AccountId, namespace: NamespaceId, claim: &ClaimOf<T>) {
+			let decrease = |count: &mut Option<u32>| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			};
+			if namespace == DEFAULT_NAMESPACE {
+				ClaimsByOwner::<T>::remove(who, claim);
+				ClaimCount::<T>::mutate_exists(who, decrease);
+			} else {
+				NamespacedClaimCount::<T>::mutate_exists(who, decrease);
+			}
+		}
+
+		fn decrease_commitment_count(who: &T::AccountId) {
+			CommitmentCount::<T>::mutate_exists(who, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// `who` 是否为命名空间的拥有者
+		fn ensure_namespace_owner(who: &T::AccountId, namespace: NamespaceId) -> DispatchResult {
+			let info = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(&info.owner == who, Error::<T>::NotNamespaceOwner);
+			Ok(())
+		}
+
+		/// 可以为 `who` 支付 `fee` 手续费的代付人, 没有代付或预算不足时为 `None`
+		pub fn sponsor_of(who: &T::AccountId, fee: BalanceOf<T>) -> Option<T::AccountId> {
+			Sponsorships::<T>::get(who)
+				.map(|sponsorship| sponsorship.sponsor)
+				.filter(|sponsor| SponsorBudgets::<T>::get(sponsor) >= fee)
+		}
+
+		/// 扣减 `who` 的代付次数和代付人的预算, 并解锁 `fee` 用于支付手续费.
+		/// 交易执行期间记录代付人, 由其预算支付存证押金
+		pub fn use_sponsorship(who: &T::AccountId, sponsor: &T::AccountId, fee: BalanceOf<T>) {
+			ActiveSponsorship::<T>::put((who.clone(), sponsor.clone()));
+			Sponsorships::<T>::mutate_exists(who, |sponsorship| {
+				*sponsorship = sponsorship
+					.take()
+					.map(|mut sponsorship| {
+						sponsorship.remaining = sponsorship.remaining.saturating_sub(1);
+						sponsorship
+					})
+					.filter(|sponsorship| sponsorship.remaining > 0);
+			});
+			SponsorBudgets::<T>::mutate_exists(sponsor, |budget| {
+				*budget = budget
+					.map(|budget| budget.saturating_sub(fee))
+					.filter(|budget| !budget.is_zero());
+			});
+			T::Currency::unreserve(sponsor, fee);
+		}
+
+		/// 锁定存证押金: 代付交易中优先从代付人的预算支付, 预算不足时由 `who` 锁定
+		fn reserve_claim_deposit(
+			who: &T::AccountId,
+			claim: &ClaimOf<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			if let Some((signer, sponsor)) = ActiveSponsorship::<T>::get() {
+				let budget = SponsorBudgets::<T>::get(&sponsor);
+				if &signer == who && budget >= deposit {
+					// 预算本身就是代付人锁定的余额, 只需从预算中扣除
+					SponsorBudgets::<T>::mutate_exists(&sponsor, |value| {
+						*value = Some(budget - deposit).filter(|budget| !budget.is_zero());
+					});
+					DepositPayers::<T>::insert(claim, sponsor);
+					return Ok(())
+				}
+			}
+			T::Currency::reserve(who, deposit)
+		}
+
+		/// 将多扣的手续费重新锁定为代付预算
+		pub fn refund_sponsorship(sponsor: &T::AccountId, amount: BalanceOf<T>) {
+			if amount.is_zero() || T::Currency::reserve(sponsor, amount).is_err() {
+				return
+			}
+			SponsorBudgets::<T>::mutate(sponsor, |budget| *budget = budget.saturating_add(amount));
+		}
+
+		/// 分页列出 `who` 拥有的存证, 跳过前 `offset` 个, 最多返回 `limit` 个
+		pub fn claims_of(who: &T::AccountId, offset: u32, limit: u32) -> Vec<ClaimOf<T>> {
+			ClaimsByOwner::<T>::iter_key_prefix(who)
+				.skip(offset as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// 分页列出命名空间中的存证, 跳过前 `offset` 个, 最多返回 `limit` 个
+		pub fn namespace_claims(
+			namespace: NamespaceId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<ClaimOf<T>> {
+			Proofs::<T>::iter_key_prefix(namespace)
+				.skip(offset as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// 查询命名空间中的存证记录
+		pub fn namespaced_claim(
+			namespace: NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>> {
+			ClaimOf::<T>::try_from(claim)
+				.ok()
+				.and_then(|claim| Proofs::<T>::get(namespace, claim))
+		}
+
+		/// 元数据需要锁定的押金
+		pub fn metadata_deposit(metadata: &MetadataOf<T>) -> BalanceOf<T> {
+			T::MetadataDepositPerByte::get()
+				.saturating_mul((metadata.encoded_size() as u32).into())
+				.saturating_add(T::MetadataDepositBase::get())
+		}
+
+		/// 查询命名空间中的存证详情, 存证不存在或超长时返回 `None`;
+		/// 其他命名空间中的存证没有附加数据
+		pub fn claim_details(
+			namespace: NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<ClaimDetails<T::AccountId, T::BlockNumber, BalanceOf<T>>> {
+			let claim = ClaimOf::<T>::try_from(claim).ok()?;
+			let ClaimInfo { owner, created_at, updated_at, deposit } =
+				Proofs::<T>::get(namespace, &claim)?;
+			if namespace != DEFAULT_NAMESPACE {
+				return Some(ClaimDetails {
+					owner,
+					created_at,
+					updated_at,
+					deposit,
+					expires_at: None,
+					metadata: None,
+					superseded_by: None,
+					lock: None,
+				})
+			}
+			let expires_at = ClaimExpiry::<T>::get(&claim);
+			let metadata = ClaimMetadataOf::<T>::get(&claim).map(|(metadata, _)| ClaimMetadata {
+				content_type: metadata.content_type.into_inner(),
+				size: metadata.size,
+				description: metadata.description.into_inner(),
+				uri: metadata.uri.into_inner(),
+			});
+			let superseded_by = SupersededBy::<T>::get(&claim).map(|claim| claim.into_inner());
+			let now = frame_system::Pallet::<T>::block_number();
+			let lock = ClaimLocks::<T>::get(&claim).filter(|lock| lock.is_active(&now));
+			Some(ClaimDetails {
+				owner,
+				created_at,
+				updated_at,
+				deposit,
+				expires_at,
+				metadata,
+				superseded_by,
+				lock,
+			})
+		}
+
+		/// 存证最近的历任拥有者, 按时间顺序排列, 不包括当前拥有者
+		pub fn claim_history(claim: Vec<u8>) -> Vec<OwnershipRecord<T::AccountId, T::BlockNumber>> {
+			ClaimOf::<T>::try_from(claim)
+				.map(|claim| ClaimHistory::<T>::get(&claim).into_inner())
+				.unwrap_or_default()
+		}
+
+		/// 存证所在的版本链, 从最早的版本到最新的版本; 存证不存在时为空
+		pub fn claim_versions(claim: Vec<u8>) -> Vec<Vec<u8>> {
+			let mut claim = match ClaimOf::<T>::try_from(claim) {
+				Ok(claim) if Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &claim) => claim,
+				_ => return Vec::new(),
+			};
+			while let Some(prev) = Supersedes::<T>::get(&claim) {
+				claim = prev;
+			}
+			let mut versions = Vec::new();
+			let mut next = Some(claim);
+			while let Some(claim) = next {
+				next = SupersededBy::<T>::get(&claim);
+				versions.push(claim.into_inner());
+			}
+			versions
+		}
+
+		/// 验证命名空间中的存证 `claim` 是否存在且属于 `owner`
+		pub fn verify_claim(namespace: NamespaceId, claim: Vec<u8>, owner: &T::AccountId) -> bool {
+			ClaimOf::<T>::try_from(claim)
+				.ok()
+				.and_then(|claim| Proofs::<T>::get(namespace, claim))
+				.map_or(false, |info| &info.owner == owner)
+		}
+
+		/// 验证 `leaf` 是否是已锚定的 Merkle 根 `root` 下的第 `leaf_index` 个叶子
+		pub fn verify_anchored_leaf(
+			root: T::Hash,
+			leaf: T::Hash,
+			leaf_index: u32,
+			proof: &[T::Hash],
+		) -> bool {
+			Anchors::<T>::get(&root).map_or(false, |anchor| {
+				merkle::verify_proof::<T::Hashing>(
+					&root,
+					leaf,
+					leaf_index,
+					anchor.leaf_count,
+					proof,
+				)
+			})
+		}
+
+		/// 存证 `claim` 加盐 `salt` 后的承诺哈希: `hash(claim ‖ salt)`
+		pub fn commitment_of(claim: &[u8], salt: &[u8; 32]) -> T::Hash {
+			T::Hashing::hash(&[claim, &salt[..]].concat())
+		}
+
+		/// 计算存证所需的押金: base + per_byte * len
+		pub fn claim_deposit(len: usize) -> BalanceOf<T> {
+			T::ClaimDepositPerByte::get()
+				.saturating_mul((len as u32).into())
+				.saturating_add(T::ClaimDepositBase::get())
+		}
+
+		/// 证明人需要签名的消息, 包含创世区块哈希以防跨链重放
+		pub fn attestation_message(
+			payload: &AttestationPayload<T::AccountId, ClaimOf<T>>,
+		) -> Vec<u8> {
+			let genesis = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+			(ATTESTATION_CONTEXT, genesis, payload).encode()
+		}
+
+		fn verify_attestation(
+			payload: &AttestationPayload<T::AccountId, ClaimOf<T>>,
+			signature: &T::AttesterSignature,
+		) -> bool {
+			signature.verify(&Self::attestation_message(payload)[..], &payload.attester)
+		}
+
+		/// 检查证明人存证交易: 证明人已登记, 序号未使用, 签名有效, 存证不存在
+		fn validate_attestation(
+			payload: &AttestationPayload<T::AccountId, ClaimOf<T>>,
+			signature: &T::AttesterSignature,
+		) -> TransactionValidity {
+			if !Attesters::<T>::contains_key(&payload.attester) {
+				return InvalidTransaction::BadSigner.into()
+			}
+			let nonce = AttesterNonces::<T>::get(&payload.attester);
+			if payload.nonce < nonce {
+				return InvalidTransaction::Stale.into()
+			}
+			if !Self::verify_attestation(payload, signature) {
+				return InvalidTransaction::BadProof.into()
+			}
+			if Proofs::<T>::contains_key(DEFAULT_NAMESPACE, &payload.claim) ||
+				Self::has_pending_cosign(&payload.claim)
+			{
+				return InvalidTransaction::Call.into()
+			}
+
+			let mut tx = ValidTransaction::with_tag_prefix("PoeAttestation")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((&payload.attester, payload.nonce))
+				.longevity(ATTESTATION_LONGEVITY)
+				.propagate(true);
+			if payload.nonce > nonce {
+				tx = tx.and_requires((&payload.attester, payload.nonce - 1));
+			}
+			tx.build()
+		}
 	}
 }
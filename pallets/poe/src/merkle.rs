@@ -0,0 +1,91 @@
+//! 二叉 Merkle 树
+//!
+//! 叶子按顺序两两组合, 父节点为 `H(left ++ right)`; 某一层节点数为奇数时, 最后一个节点原样提升到
+//! 上一层。批量存证时客户端只需将根锚定上链, 之后凭包含证明即可证明单个文件的存在。
+
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+fn hash_node<H: Hash>(left: &H::Output, right: &H::Output) -> H::Output {
+	let mut data = Vec::with_capacity(left.as_ref().len() + right.as_ref().len());
+	data.extend_from_slice(left.as_ref());
+	data.extend_from_slice(right.as_ref());
+	H::hash(&data)
+}
+
+fn next_layer<H: Hash>(layer: &[H::Output]) -> Vec<H::Output> {
+	layer
+		.chunks(2)
+		.map(|pair| match pair {
+			[left, right] => hash_node::<H>(left, right),
+			[single] => single.clone(),
+			_ => unreachable!("chunks(2) yields one or two items; qed"),
+		})
+		.collect()
+}
+
+/// 计算 `leaves` 的 Merkle 根, 没有叶子时返回 `None`
+pub fn merkle_root<H: Hash>(leaves: &[H::Output]) -> Option<H::Output> {
+	let mut layer = leaves.to_vec();
+	if layer.is_empty() {
+		return None
+	}
+	while layer.len() > 1 {
+		layer = next_layer::<H>(&layer);
+	}
+	layer.pop()
+}
+
+/// 生成第 `leaf_index` 个叶子的包含证明, 下标越界时返回 `None`
+pub fn merkle_proof<H: Hash>(leaves: &[H::Output], leaf_index: u32) -> Option<Vec<H::Output>> {
+	let mut index = leaf_index as usize;
+	if index >= leaves.len() {
+		return None
+	}
+	let mut proof = Vec::new();
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		let sibling = index ^ 1;
+		if sibling < layer.len() {
+			proof.push(layer[sibling].clone());
+		}
+		layer = next_layer::<H>(&layer);
+		index /= 2;
+	}
+	Some(proof)
+}
+
+/// 验证 `leaf` 是第 `leaf_index` 个叶子且包含在共有 `leaf_count` 个叶子、根为 `root` 的树中
+pub fn verify_proof<H: Hash>(
+	root: &H::Output,
+	leaf: H::Output,
+	leaf_index: u32,
+	leaf_count: u32,
+	proof: &[H::Output],
+) -> bool {
+	if leaf_index >= leaf_count {
+		return false
+	}
+	let mut node = leaf;
+	let mut index = leaf_index;
+	let mut width = leaf_count;
+	let mut proof = proof.iter();
+	while width > 1 {
+		// 奇数层的最后一个节点没有兄弟节点, 直接提升
+		let promoted = index == width - 1 && width % 2 == 1;
+		if !promoted {
+			let sibling = match proof.next() {
+				Some(sibling) => sibling,
+				None => return false,
+			};
+			node = if index % 2 == 0 {
+				hash_node::<H>(&node, sibling)
+			} else {
+				hash_node::<H>(sibling, &node)
+			};
+		}
+		index /= 2;
+		width = (width + 1) / 2;
+	}
+	proof.next().is_none() && node == *root
+}
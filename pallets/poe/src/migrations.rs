@@ -0,0 +1,262 @@
+//! pallet-poe 的存储迁移
+
+use super::*;
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
+
+/// v0 → v1: `Proofs` 的值由元组改为 [`ClaimInfo`](v1::ClaimInfo), 并为存证补建拥有者索引
+pub mod v1 {
+	use super::*;
+
+	/// v1 的存证记录
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		pub owner: AccountId,
+		pub created_at: BlockNumber,
+		pub deposit: Balance,
+	}
+
+	pub type ClaimInfoOf<T> = ClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+
+	/// v1 的 `Proofs`
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ClaimOf<T>, ClaimInfoOf<T>>;
+
+	/// v0 的存证记录
+	///
+	/// 最初为 `(拥有者, 区块高度)`, 之后未升级版本就追加了押金, 两种编码都要能解码.
+	/// 没有押金的存证按零押金迁移.
+	pub struct OldClaimInfo<AccountId, BlockNumber, Balance>(AccountId, BlockNumber, Balance);
+
+	impl<AccountId: Decode, BlockNumber: Decode, Balance: Decode + Zero> Decode
+		for OldClaimInfo<AccountId, BlockNumber, Balance>
+	{
+		fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+			let owner = AccountId::decode(input)?;
+			let created_at = BlockNumber::decode(input)?;
+			let deposit = if input.remaining_len()? == Some(0) {
+				Zero::zero()
+			} else {
+				Balance::decode(input)?
+			};
+			Ok(Self(owner, created_at, deposit))
+		}
+	}
+
+	type OldClaimInfoOf<T> = OldClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+
+	/// 将 v0 的存证记录迁移为 [`ClaimInfo`]
+	///
+	/// 加入 `ClaimsByOwner` 和 `ClaimCount` 之前创建的存证没有索引, 迁移时一并补上; 已有索引的
+	/// 存证不会被重复计数。补建的索引不受 `MaxClaimsPerAccount` 限制。
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: "runtime::poe",
+					"skipping v1 migration, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			let mut indexed = 0u64;
+			Proofs::<T>::translate::<OldClaimInfoOf<T>, _>(
+				|claim, OldClaimInfo(owner, created_at, deposit)| {
+					translated += 1;
+					if !ClaimsByOwner::<T>::contains_key(&owner, &claim) {
+						indexed += 1;
+						ClaimsByOwner::<T>::insert(&owner, &claim, ());
+						ClaimCount::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
+					}
+					Some(ClaimInfo { owner, created_at, deposit })
+				},
+			);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::poe",
+				"migrated {} claims to v1, indexed {} of them",
+				translated,
+				indexed
+			);
+			// 每个存证读写一次并检查索引, 补建索引时读写计数, 外加存储版本
+			T::DbWeight::get()
+				.reads_writes(translated * 2 + indexed + 1, translated + indexed * 2 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			// 迁移不会执行时存储已是其他格式, 不按 v0 读取
+			let count = if on_chain == 0 { Proofs::<T>::iter_keys().count() as u64 } else { 0 };
+			Ok((on_chain, count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (on_chain, count) = <(StorageVersion, u64)>::decode(&mut &state[..])
+				.map_err(|_| "invalid pre-upgrade state")?;
+			if on_chain != 0 {
+				return Ok(())
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+			ensure!(
+				Proofs::<T>::iter_values().count() as u64 == count,
+				"some claims could not be decoded after migration"
+			);
+			ensure!(
+				Proofs::<T>::iter()
+					.all(|(claim, info)| ClaimsByOwner::<T>::contains_key(&info.owner, &claim)),
+				"some claims are missing from the owner index"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// v1 → v2: [`ClaimInfo`] 增加 `updated_at`, 迁移时取创建时的区块高度
+pub mod v2 {
+	use super::*;
+
+	/// v2 的存证记录, 与 v3 相同
+	pub type ClaimInfoOf<T> = ClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+	>;
+
+	/// v2 的 `Proofs`
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ClaimOf<T>, ClaimInfoOf<T>>;
+
+	/// 为 v1 的存证记录补充 `updated_at`
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 1 {
+				log::info!(
+					target: "runtime::poe",
+					"skipping v2 migration, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Proofs::<T>::translate_values::<v1::ClaimInfoOf<T>, _>(|old| {
+				translated += 1;
+				Some(ClaimInfo {
+					owner: old.owner,
+					created_at: old.created_at,
+					updated_at: old.created_at,
+					deposit: old.deposit,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "runtime::poe", "migrated {} claims to v2", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			let count = if on_chain == 1 { Proofs::<T>::iter_keys().count() as u64 } else { 0 };
+			Ok((on_chain, count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (on_chain, count) = <(StorageVersion, u64)>::decode(&mut &state[..])
+				.map_err(|_| "invalid pre-upgrade state")?;
+			if on_chain != 1 {
+				return Ok(())
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+			ensure!(
+				Proofs::<T>::iter_values().all(|info| info.updated_at >= info.created_at),
+				"claim updated before it was created"
+			);
+			ensure!(
+				Proofs::<T>::iter_values().count() as u64 == count,
+				"some claims could not be decoded after migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// v2 → v3: `Proofs` 以 `(命名空间, 存证)` 为键, 已有存证迁移到默认命名空间
+pub mod v3 {
+	use super::*;
+
+	/// 将 v2 的存证移入 [`DEFAULT_NAMESPACE`]
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 2 {
+				log::info!(
+					target: "runtime::poe",
+					"skipping v3 migration, on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			// 新旧存储的前缀相同, 先全部取出再写入, 以免遍历到新写入的键
+			let claims = v2::Proofs::<T>::drain().collect::<Vec<_>>();
+			let moved = claims.len() as u64;
+			for (claim, info) in claims {
+				Proofs::<T>::insert(DEFAULT_NAMESPACE, claim, info);
+			}
+			StorageVersion::new(3).put::<Pallet<T>>();
+			log::info!(target: "runtime::poe", "moved {} claims to the default namespace", moved);
+			// 每个存证一次读取、删除旧键和写入新键两次写入, 外加存储版本
+			T::DbWeight::get().reads_writes(moved + 1, moved * 2 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			let count = if on_chain == 2 { v2::Proofs::<T>::iter_keys().count() as u64 } else { 0 };
+			Ok((on_chain, count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (on_chain, count) = <(StorageVersion, u64)>::decode(&mut &state[..])
+				.map_err(|_| "invalid pre-upgrade state")?;
+			if on_chain != 2 {
+				return Ok(())
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 3, "storage version not updated");
+			ensure!(
+				Proofs::<T>::iter_prefix_values(DEFAULT_NAMESPACE).count() as u64 == count,
+				"some claims were not moved to the default namespace"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,13 @@
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, ConstU8},
+	weights::{ConstantMultiplier, IdentityFee},
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 
@@ -18,7 +22,9 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
+		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 	}
 );
 
@@ -40,7 +46,7 @@ impl system::Config for Test {
 	type DbWeight = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -49,12 +55,92 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type HoldIdentifier = ();
+	type MaxHolds = ();
+}
+
+// 手续费只按交易长度计算, 每字节 1
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = ConstantMultiplier<u64, ConstU64<0>>;
+	type LengthToFee = IdentityFee<u64>;
+	type FeeMultiplierUpdate = ();
+}
+
+parameter_types! {
+	pub static AllowDirectTransfer: bool = true;
+}
+
 impl pallet_poe::Config for Test {
 	type MaxClaimLength = ConstU32<512>;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ClaimDepositBase = ConstU64<10>;
+	type ClaimDepositPerByte = ConstU64<1>;
+	type MaxClaimsPerBatch = ConstU32<4>;
+	type MaxClaimLifetime = ConstU64<100>;
+	type MaxExpiringPerBlock = ConstU32<2>;
+	type MaxClaimsPerAccount = ConstU32<5>;
+	type AllowDirectTransfer = AllowDirectTransfer;
+	type MaxContentTypeLength = ConstU32<16>;
+	type MaxDescriptionLength = ConstU32<32>;
+	type MaxUriLength = ConstU32<32>;
+	type MetadataDepositBase = ConstU64<5>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type MaxHistoryLength = ConstU32<2>;
+	type MaxOperators = ConstU32<2>;
+	type ForceOrigin = system::EnsureRoot<u64>;
+	type MaxSigners = ConstU32<3>;
+	type MaxCosignPeriod = ConstU64<20>;
+	type AttesterPublic = UintAuthorityId;
+	type AttesterSignature = TestSignature;
+	type UnsignedPriority = ConstU64<100>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
+	type CommitmentDeposit = ConstU64<3>;
+	type RevealPeriod = ConstU64<10>;
+	type MaxCommitmentsPerBlock = ConstU32<2>;
+	type MaxCommitmentsPerAccount = ConstU32<1>;
+	type NamespaceDeposit = ConstU64<20>;
+	type ArbiterOrigin = system::EnsureRoot<u64>;
+	type ChallengeBond = ConstU64<15>;
+	type Slashed = ();
+	type WeightInfo = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<u64, TestSignature> for BenchmarkHelper {
+	fn attester() -> u64 {
+		7
+	}
+
+	fn sign(attester: &u64, message: &[u8]) -> TestSignature {
+		TestSignature(*attester, message.to_vec())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100), (3, 5)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
 }
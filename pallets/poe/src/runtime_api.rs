@@ -0,0 +1,51 @@
+//! pallet-poe 的 runtime API
+
+use crate::{ClaimDetails, ClaimInfo, NamespaceId, NamespaceInfo, OwnershipRecord};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// 存证查询接口
+	pub trait PoeApi<AccountId, BlockNumber, Balance, Hash> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+		Hash: Codec,
+	{
+		/// 查询命名空间中的存证详情, 默认命名空间为 `0`
+		fn get_claim(
+			namespace: NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<ClaimDetails<AccountId, BlockNumber, Balance>>;
+
+		/// 存证最近的历任拥有者, 按时间顺序排列, 最多 `MaxHistoryLength` 条
+		fn claim_history(claim: Vec<u8>) -> Vec<OwnershipRecord<AccountId, BlockNumber>>;
+
+		/// 存证所在的版本链, 从最早的版本到最新的版本
+		fn claim_versions(claim: Vec<u8>) -> Vec<Vec<u8>>;
+
+		/// 验证命名空间中的存证 `claim` 是否存在且属于 `owner`
+		fn verify_claim(namespace: NamespaceId, claim: Vec<u8>, owner: AccountId) -> bool;
+
+		/// `who` 在默认命名空间中拥有的存证数量
+		fn claim_count(who: AccountId) -> u32;
+
+		/// 分页列出 `who` 拥有的存证, 跳过前 `offset` 个, 最多返回 `limit` 个
+		fn claims_of(who: AccountId, offset: u32, limit: u32) -> Vec<Vec<u8>>;
+
+		/// 验证 `leaf` 是否是已锚定的 Merkle 根 `root` 下的第 `leaf_index` 个叶子
+		fn verify_anchored_leaf(root: Hash, leaf: Hash, leaf_index: u32, proof: Vec<Hash>) -> bool;
+
+		/// 查询命名空间
+		fn get_namespace(namespace: NamespaceId) -> Option<NamespaceInfo<AccountId, Balance>>;
+
+		/// 查询命名空间中的存证
+		fn get_namespaced_claim(
+			namespace: NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<ClaimInfo<AccountId, BlockNumber, Balance>>;
+
+		/// 分页列出命名空间中的存证, 跳过前 `offset` 个, 最多返回 `limit` 个
+		fn namespace_claims(namespace: NamespaceId, offset: u32, limit: u32) -> Vec<Vec<u8>>;
+	}
+}
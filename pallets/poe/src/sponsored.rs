@@ -0,0 +1,182 @@
+//! 由代付人支付手续费的 PoE 交易
+//!
+//! 代付人通过 `fund_sponsorship` 锁定代付预算, 再用 `sponsor_account` 为账户设置代付次数。被代付的
+//! 账户发送 PoE 交易时, [`ChargeSponsoredFee`] 从代付人的预算中扣除手续费, 预算或次数用完后
+//! 由签名账户自己支付。
+//!
+//! 代付交易中创建存证的押金同样从预算中扣除并记录在 `DepositPayers` 中, 存证撤销或过期时退回
+//! 代付人的预算; 预算不足以支付押金时押金仍从签名账户锁定。
+
+use super::*;
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::IsSubType,
+};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, Saturating,
+		SignedExtension, Zero,
+	},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	DispatchResult, FixedPointOperand,
+};
+
+type FeeOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<
+	T,
+>>::Balance;
+type LiquidityInfoOf<T> =
+	<<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<
+		T,
+	>>::LiquidityInfo;
+
+fn to_balance<T: Config + pallet_transaction_payment::Config>(fee: FeeOf<T>) -> BalanceOf<T> {
+	fee.saturated_into::<u128>().saturated_into()
+}
+
+/// 代替 [`ChargeTransactionPayment`] 收取手续费, 签名账户有代付时由代付人支付 PoE 交易的手续费
+///
+/// 编码与 [`ChargeTransactionPayment`] 相同 (只包含小费), 并沿用其标识, 钱包无需改动即可签名。
+/// 带小费的交易和其他模块的交易总是由签名账户支付。
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeSponsoredFee<T: Config + pallet_transaction_payment::Config>(
+	#[codec(compact)] FeeOf<T>,
+);
+
+impl<T: Config + pallet_transaction_payment::Config> ChargeSponsoredFee<T> {
+	/// 附带 `tip` 小费
+	pub fn from(tip: FeeOf<T>) -> Self {
+		Self(tip)
+	}
+}
+
+impl<T> ChargeSponsoredFee<T>
+where
+	T: Config + pallet_transaction_payment::Config,
+	FeeOf<T>: FixedPointOperand,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
+{
+	/// 为 `who` 支付 `call` 手续费的代付人, 以及预计的手续费
+	fn sponsor(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+	) -> Option<(T::AccountId, FeeOf<T>)> {
+		if !self.0.is_zero() || call.is_sub_type().is_none() {
+			return None
+		}
+		let fee =
+			pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero());
+		Pallet::<T>::sponsor_of(who, to_balance::<T>(fee)).map(|sponsor| (sponsor, fee))
+	}
+}
+
+impl<T: Config + pallet_transaction_payment::Config> sp_std::fmt::Debug for ChargeSponsoredFee<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeSponsoredFee<{:?}>", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> SignedExtension for ChargeSponsoredFee<T>
+where
+	T: Config + pallet_transaction_payment::Config,
+	FeeOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// 代付时为 `(签名账户, 代付人, 预扣的手续费)`, 以及 [`ChargeTransactionPayment`] 的 `Pre`
+	type Pre = (
+		Option<(T::AccountId, T::AccountId, FeeOf<T>)>,
+		(FeeOf<T>, T::AccountId, LiquidityInfoOf<T>),
+	);
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.sponsor(who, call, info, len) {
+			// 交易池中的验证不能修改存储, 只检查预算; 预算在 pre_dispatch 中才解锁并扣除
+			Some((_, fee)) => Ok(ValidTransaction {
+				priority: ChargeTransactionPayment::<T>::get_priority(info, len, Zero::zero(), fee),
+				..Default::default()
+			}),
+			None => ChargeTransactionPayment::<T>::from(self.0).validate(who, call, info, len),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match self.sponsor(who, call, info, len) {
+			Some((sponsor, fee)) => {
+				// 先从预算中解锁手续费, 再像普通交易一样从代付人处收取
+				Pallet::<T>::use_sponsorship(who, &sponsor, to_balance::<T>(fee));
+				let pre = ChargeTransactionPayment::<T>::from(Zero::zero())
+					.pre_dispatch(&sponsor, call, info, len)?;
+				Ok((Some((who.clone(), sponsor, fee)), pre))
+			},
+			None => Ok((
+				None,
+				ChargeTransactionPayment::<T>::from(self.0).pre_dispatch(who, call, info, len)?,
+			)),
+		}
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (sponsored, pre) = match pre {
+			Some((sponsored, pre)) => (sponsored, Some(pre)),
+			None => (None, None),
+		};
+		ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result)?;
+		if let Some((who, sponsor, fee)) = sponsored {
+			ActiveSponsorship::<T>::kill();
+			let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len as u32,
+				info,
+				post_info,
+				Zero::zero(),
+			);
+			// 多扣的手续费已退还给代付人, 重新计入预算
+			Pallet::<T>::refund_sponsorship(
+				&sponsor,
+				to_balance::<T>(fee.saturating_sub(actual_fee)),
+			);
+			Pallet::<T>::deposit_event(Event::FeeSponsored {
+				sponsor,
+				who,
+				fee: to_balance::<T>(actual_fee),
+			});
+		}
+		Ok(())
+	}
+}
@@ -1,4 +1,17 @@
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::Hooks,
+	BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Dispatchable, Hash, SignedExtension, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+	DispatchResult,
+};
 
 use crate::{Error, mock::*};
 
@@ -6,6 +19,15 @@ use super::*;
 
 const ACCOUNT_ONE: u64 = 1;
 const ACCOUNT_TWO: u64 = 2;
+const ACCOUNT_POOR: u64 = 3;
+
+// ClaimDepositBase(10) + ClaimDepositPerByte(1) * 2 字节
+const CLAIM_DEPOSIT: u64 = 12;
+
+/// 创建后拥有者未变更过的存证记录
+fn claim_info(owner: u64, created_at: u64, deposit: u64) -> ClaimInfo<u64, u64, u64> {
+	ClaimInfo { owner, created_at, updated_at: created_at, deposit }
+}
 
 #[test]
 fn create_claim_works() {
@@ -13,8 +35,8 @@ fn create_claim_works() {
 		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
 			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
 			assert_eq!(
-				Proofs::<Test>::get(&claim),
-				Some((ACCOUNT_ONE, frame_system::Pallet::<Test>::block_number()))
+				Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+				Some(claim_info(ACCOUNT_ONE, System::block_number(), CLAIM_DEPOSIT))
 			);
 		}
 	})
@@ -74,8 +96,8 @@ fn transfer_claim_works() {
 		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
 			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
 			assert_eq!(
-				Proofs::<Test>::get(&claim),
-				Some((ACCOUNT_ONE, frame_system::Pallet::<Test>::block_number()))
+				Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+				Some(claim_info(ACCOUNT_ONE, System::block_number(), CLAIM_DEPOSIT))
 			);
 			assert_ok!(
 				PoeModule::transfer_claim(
@@ -85,8 +107,8 @@ fn transfer_claim_works() {
 				)
 			);
 			assert_eq!(
-				Proofs::<Test>::get(&claim),
-				Some((ACCOUNT_TWO, frame_system::Pallet::<Test>::block_number()))
+				Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+				Some(claim_info(ACCOUNT_TWO, System::block_number(), CLAIM_DEPOSIT))
 			);
 		}
 	})
@@ -114,8 +136,8 @@ fn transfer_failed_with_wrong_owner() {
 		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
 			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
 			assert_eq!(
-				Proofs::<Test>::get(&claim),
-				Some((ACCOUNT_ONE, frame_system::Pallet::<Test>::block_number()))
+				Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+				Some(claim_info(ACCOUNT_ONE, System::block_number(), CLAIM_DEPOSIT))
 			);
 			assert_noop!(
 				PoeModule::transfer_claim(
@@ -145,3 +167,2560 @@ fn transfer_failed_with_self_transfer() {
 		}
 	})
 }
+
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
+			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim));
+			assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT);
+			assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100 - CLAIM_DEPOSIT);
+		}
+	})
+}
+
+#[test]
+fn create_claim_failed_when_deposit_cannot_be_reserved() {
+	new_test_ext().execute_with(|| {
+		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
+			assert_noop!(
+				PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_POOR), claim),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		}
+	})
+}
+
+#[test]
+fn revoke_claim_unreserves_deposit() {
+	new_test_ext().execute_with(|| {
+		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
+			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+			assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim));
+			assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+			assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100);
+		}
+	})
+}
+
+#[test]
+fn transfer_claim_moves_deposit_to_new_owner() {
+	new_test_ext().execute_with(|| {
+		if let Ok(claim) = BoundedVec::try_from(vec![0, 1]) {
+			assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+			assert_ok!(PoeModule::transfer_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claim.clone()
+			));
+			assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+			assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100 - CLAIM_DEPOSIT);
+			assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CLAIM_DEPOSIT);
+
+			assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim));
+			assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+			assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100 + CLAIM_DEPOSIT);
+		}
+	})
+}
+
+#[test]
+fn create_digest_claim_works() {
+	new_test_ext().execute_with(|| {
+		let digest = ClaimDigest::sha2_256(b"hello poe");
+		assert_eq!(digest.encoded_size() as u32, DIGEST_CLAIM_LEN);
+
+		assert_ok!(PoeModule::create_digest_claim(RuntimeOrigin::signed(ACCOUNT_ONE), digest));
+		let claim = PoeModule::digest_claim(&digest).unwrap();
+		assert_eq!(claim.to_vec(), digest.encode());
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).map(|info| info.owner),
+			Some(ACCOUNT_ONE)
+		);
+
+		assert_noop!(
+			PoeModule::create_digest_claim(RuntimeOrigin::signed(ACCOUNT_TWO), digest),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim));
+	})
+}
+
+#[test]
+fn digest_claims_are_distinct_per_algorithm() {
+	new_test_ext().execute_with(|| {
+		let data = b"hello poe";
+		let blake2 = ClaimDigest::blake2_256(data);
+		let keccak = ClaimDigest::keccak_256(data);
+		assert_ok!(PoeModule::create_digest_claim(RuntimeOrigin::signed(ACCOUNT_ONE), blake2));
+		assert_ok!(PoeModule::create_digest_claim(RuntimeOrigin::signed(ACCOUNT_ONE), keccak));
+
+		assert!(blake2.verify(data));
+		assert!(keccak.verify(data));
+		assert!(!blake2.verify(b"tampered"));
+	})
+}
+
+fn claims(items: Vec<Vec<u8>>) -> BoundedVec<ClaimOf<Test>, <Test as Config>::MaxClaimsPerBatch> {
+	BoundedVec::try_from(
+		items
+			.into_iter()
+			.map(|item| BoundedVec::try_from(item).unwrap())
+			.collect::<Vec<_>>(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn batch_claims_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let batch = claims(vec![vec![0], vec![1], vec![2]]);
+
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(ACCOUNT_ONE), batch.clone()));
+		assert!(batch.iter().all(|claim| Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, claim)));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 3 * 11);
+		for claim in batch.iter() {
+			System::assert_has_event(
+				Event::<Test>::ClaimCreated { who: ACCOUNT_ONE, claim: claim.clone(), block: 1 }
+					.into(),
+			);
+		}
+
+		assert_ok!(PoeModule::transfer_claims(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			batch.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 3 * 11);
+
+		assert_ok!(PoeModule::revoke_claims(RuntimeOrigin::signed(ACCOUNT_TWO), batch.clone()));
+		assert!(batch
+			.iter()
+			.all(|claim| !Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, claim)));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+	})
+}
+
+#[test]
+fn batch_claims_are_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claims(vec![vec![2]])
+		));
+
+		assert_noop!(
+			PoeModule::create_claims(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claims(vec![vec![0], vec![1], vec![2]])
+			),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_noop!(
+			PoeModule::revoke_claims(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claims(vec![vec![2], vec![3]])
+			),
+			Error::<Test>::ClaimNotExist
+		);
+	})
+}
+
+fn leaves(n: u8) -> Vec<H256> {
+	(0..n).map(|i| BlakeTwo256::hash(&[i])).collect()
+}
+
+#[test]
+fn merkle_proofs_verify_for_every_leaf() {
+	for n in 1..=9 {
+		let leaves = leaves(n);
+		let root = merkle::merkle_root::<BlakeTwo256>(&leaves).unwrap();
+		for (index, leaf) in leaves.iter().enumerate() {
+			let proof = merkle::merkle_proof::<BlakeTwo256>(&leaves, index as u32).unwrap();
+			assert!(merkle::verify_proof::<BlakeTwo256>(
+				&root,
+				*leaf,
+				index as u32,
+				n as u32,
+				&proof
+			));
+			assert!(!merkle::verify_proof::<BlakeTwo256>(
+				&root,
+				*leaf,
+				index as u32,
+				n as u32 + 1,
+				&proof
+			));
+		}
+	}
+	assert_eq!(merkle::merkle_root::<BlakeTwo256>(&[]), None);
+}
+
+#[test]
+fn anchor_root_works() {
+	new_test_ext().execute_with(|| {
+		let leaves = leaves(5);
+		let root = merkle::merkle_root::<BlakeTwo256>(&leaves).unwrap();
+		let proof = merkle::merkle_proof::<BlakeTwo256>(&leaves, 3).unwrap();
+		assert!(!PoeModule::verify_anchored_leaf(root, leaves[3], 3, &proof));
+
+		assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(ACCOUNT_ONE), root, 5));
+		assert_eq!(Anchors::<Test>::get(root).map(|anchor| anchor.leaf_count), Some(5));
+		assert!(PoeModule::verify_anchored_leaf(root, leaves[3], 3, &proof));
+		assert!(!PoeModule::verify_anchored_leaf(root, leaves[2], 3, &proof));
+		assert!(!PoeModule::verify_anchored_leaf(root, leaves[3], 2, &proof));
+
+		assert_noop!(
+			PoeModule::anchor_root(RuntimeOrigin::signed(ACCOUNT_TWO), root, 5),
+			Error::<Test>::AnchorAlreadyExist
+		);
+		assert_noop!(
+			PoeModule::anchor_root(RuntimeOrigin::signed(ACCOUNT_TWO), leaves[0], 0),
+			Error::<Test>::EmptyAnchor
+		);
+	})
+}
+
+#[test]
+fn remove_anchor_works() {
+	new_test_ext().execute_with(|| {
+		let root = BlakeTwo256::hash(b"root");
+		assert_ok!(PoeModule::anchor_root(RuntimeOrigin::signed(ACCOUNT_ONE), root, 1));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), PoeModule::claim_deposit(32));
+
+		assert_noop!(
+			PoeModule::remove_anchor(RuntimeOrigin::signed(ACCOUNT_TWO), root),
+			Error::<Test>::NotAnchorOwner
+		);
+		assert_ok!(PoeModule::remove_anchor(RuntimeOrigin::signed(ACCOUNT_ONE), root));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_noop!(
+			PoeModule::remove_anchor(RuntimeOrigin::signed(ACCOUNT_ONE), root),
+			Error::<Test>::AnchorNotExist
+		);
+	})
+}
+
+#[test]
+fn expiring_claim_is_removed_at_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_expiring_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			10
+		));
+		assert_eq!(PoeModule::claim_expiry(&claim), Some(10));
+		assert_eq!(ExpiryQueue::<Test>::get(10).into_inner(), vec![claim.clone()]);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+
+		PoeModule::on_initialize(9);
+		assert!(Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+
+		PoeModule::on_initialize(10);
+		assert!(!Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert_eq!(PoeModule::claim_expiry(&claim), None);
+		assert!(!ExpiryQueue::<Test>::contains_key(10));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100 + CLAIM_DEPOSIT);
+		System::assert_last_event(Event::<Test>::ClaimExpired { owner: ACCOUNT_TWO, claim }.into());
+	})
+}
+
+#[test]
+fn revoke_claim_cancels_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let other = BoundedVec::try_from(vec![2, 3]).unwrap();
+		assert_ok!(PoeModule::create_expiring_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			10
+		));
+		assert_ok!(PoeModule::create_expiring_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			other.clone(),
+			10
+		));
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_eq!(PoeModule::claim_expiry(&claim), None);
+		assert_eq!(ExpiryQueue::<Test>::get(10).into_inner(), vec![other.clone()]);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), other));
+		assert!(!ExpiryQueue::<Test>::contains_key(10));
+	})
+}
+
+#[test]
+fn create_expiring_claim_checks_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::create_expiring_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), 5),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			PoeModule::create_expiring_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				106
+			),
+			Error::<Test>::ExpiryTooFar
+		);
+
+		for item in [vec![1], vec![2]] {
+			assert_ok!(PoeModule::create_expiring_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				BoundedVec::try_from(item).unwrap(),
+				105
+			));
+		}
+		assert_noop!(
+			PoeModule::create_expiring_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim, 105),
+			Error::<Test>::TooManyExpiringClaims
+		);
+	})
+}
+
+#[test]
+fn claims_by_owner_follows_ownership() {
+	new_test_ext().execute_with(|| {
+		let batch = claims(vec![vec![0], vec![1], vec![2]]);
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(ACCOUNT_ONE), batch.clone()));
+		assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 3);
+		let mut owned = PoeModule::claims_of(&ACCOUNT_ONE, 0, 10);
+		owned.sort();
+		assert_eq!(owned, batch.clone().into_inner());
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			batch[0].clone()
+		));
+		assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 2);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_TWO, 0, 10), vec![batch[0].clone()]);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), batch[0].clone()));
+		assert_eq!(PoeModule::claim_count(ACCOUNT_TWO), 0);
+		assert!(!ClaimCount::<Test>::contains_key(ACCOUNT_TWO));
+		assert!(PoeModule::claims_of(&ACCOUNT_TWO, 0, 10).is_empty());
+	})
+}
+
+#[test]
+fn claims_of_is_paginated() {
+	new_test_ext().execute_with(|| {
+		let batch = claims(vec![vec![0], vec![1], vec![2], vec![3]]);
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(ACCOUNT_ONE), batch));
+
+		let first = PoeModule::claims_of(&ACCOUNT_ONE, 0, 3);
+		let rest = PoeModule::claims_of(&ACCOUNT_ONE, 3, 3);
+		assert_eq!(first.len(), 3);
+		assert_eq!(rest.len(), 1);
+		assert!(!first.contains(&rest[0]));
+		assert!(PoeModule::claims_of(&ACCOUNT_ONE, 4, 3).is_empty());
+	})
+}
+
+#[test]
+fn create_claim_failed_with_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claims(vec![vec![0], vec![1], vec![2], vec![3]])
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			BoundedVec::try_from(vec![4]).unwrap()
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				BoundedVec::try_from(vec![5]).unwrap()
+			),
+			Error::<Test>::TooManyClaims
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			BoundedVec::try_from(vec![5]).unwrap()
+		));
+		assert_noop!(
+			PoeModule::transfer_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				ACCOUNT_ONE,
+				BoundedVec::try_from(vec![5]).unwrap()
+			),
+			Error::<Test>::TooManyClaims
+		);
+	})
+}
+
+#[test]
+fn claim_details_and_verify_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			BoundedVec::try_from(vec![0, 1]).unwrap()
+		));
+		assert_ok!(PoeModule::create_expiring_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			BoundedVec::try_from(vec![2, 3]).unwrap(),
+			20
+		));
+
+		assert_eq!(
+			PoeModule::claim_details(DEFAULT_NAMESPACE, vec![0, 1]),
+			Some(ClaimDetails {
+				owner: ACCOUNT_ONE,
+				created_at: 5,
+				updated_at: 5,
+				deposit: CLAIM_DEPOSIT,
+				expires_at: None,
+				metadata: None,
+				superseded_by: None,
+				lock: None
+			})
+		);
+		assert_eq!(
+			PoeModule::claim_details(DEFAULT_NAMESPACE, vec![2, 3]).unwrap().expires_at,
+			Some(20)
+		);
+		assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, vec![4]), None);
+		assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, vec![0; 513]), None);
+
+		assert!(PoeModule::verify_claim(DEFAULT_NAMESPACE, vec![0, 1], &ACCOUNT_ONE));
+		assert!(!PoeModule::verify_claim(DEFAULT_NAMESPACE, vec![0, 1], &ACCOUNT_TWO));
+		assert!(!PoeModule::verify_claim(DEFAULT_NAMESPACE, vec![4], &ACCOUNT_ONE));
+	})
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone(),
+			Some(10)
+		));
+		System::assert_has_event(
+			Event::<Test>::ClaimOffered {
+				from: ACCOUNT_ONE,
+				to: ACCOUNT_TWO,
+				claim: claim.clone(),
+				expires_at: Some(10),
+			}
+			.into(),
+		);
+		// 接受之前所有权和押金都不变
+		assert_eq!(Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim).unwrap().owner, ACCOUNT_ONE);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		System::assert_has_event(
+			Event::<Test>::OfferAccepted { who: ACCOUNT_TWO, claim: claim.clone() }.into(),
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_TWO, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CLAIM_DEPOSIT);
+		assert_eq!(PoeModule::pending_transfers(&claim), None);
+	})
+}
+
+#[test]
+fn accept_claim_failed_when_not_recipient_or_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+		assert_noop!(
+			PoeModule::offer_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				ACCOUNT_ONE,
+				claim.clone(),
+				None
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::offer_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claim.clone(),
+				Some(1)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone(),
+			Some(5)
+		));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_POOR), claim.clone()),
+			Error::<Test>::NotOfferRecipient
+		);
+		System::set_block_number(6);
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::OfferExpired
+		);
+	})
+}
+
+#[test]
+fn cancel_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone(),
+			None
+		));
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		System::assert_has_event(
+			Event::<Test>::OfferCancelled { from: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+	})
+}
+
+#[test]
+fn pending_offer_is_cleared_when_claim_is_revoked() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone(),
+			None
+		));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_eq!(PoeModule::pending_transfers(&claim), None);
+
+		// 重新创建的存证不会被旧的转移带走
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+	})
+}
+
+#[test]
+fn transfer_claim_failed_when_direct_transfer_disabled() {
+	new_test_ext().execute_with(|| {
+		AllowDirectTransfer::set(false);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_noop!(
+			PoeModule::transfer_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claim.clone()
+			),
+			Error::<Test>::DirectTransferDisabled
+		);
+		assert_noop!(
+			PoeModule::transfer_claims(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claims(vec![vec![0, 1]])
+			),
+			Error::<Test>::DirectTransferDisabled
+		);
+	})
+}
+
+fn metadata(content_type: &[u8], description: &[u8], uri: &[u8]) -> MetadataOf<Test> {
+	ClaimMetadata {
+		content_type: BoundedVec::try_from(content_type.to_vec()).unwrap(),
+		size: 1024,
+		description: BoundedVec::try_from(description.to_vec()).unwrap(),
+		uri: BoundedVec::try_from(uri.to_vec()).unwrap(),
+	}
+}
+
+#[test]
+fn create_claim_with_metadata_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let meta = metadata(b"text/plain", b"hello", b"ipfs://x");
+		// 基础押金 5 + 编码长度 34
+		assert_eq!(PoeModule::metadata_deposit(&meta), 39);
+
+		assert_ok!(PoeModule::create_claim_with_metadata(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			meta.clone()
+		));
+		System::assert_has_event(
+			Event::<Test>::MetadataSet { who: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_eq!(PoeModule::claim_metadata(&claim), Some((meta, 39)));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT + 39);
+
+		let details = PoeModule::claim_details(DEFAULT_NAMESPACE, vec![0, 1]).unwrap();
+		assert_eq!(
+			details.metadata,
+			Some(ClaimMetadata {
+				content_type: b"text/plain".to_vec(),
+				size: 1024,
+				description: b"hello".to_vec(),
+				uri: b"ipfs://x".to_vec(),
+			})
+		);
+	})
+}
+
+#[test]
+fn set_claim_metadata_adjusts_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::set_claim_metadata(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				Some(metadata(b"", b"", b""))
+			),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_noop!(
+			PoeModule::set_claim_metadata(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				claim.clone(),
+				Some(metadata(b"", b"", b""))
+			),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::set_claim_metadata(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			Some(metadata(b"text/plain", b"hello", b"ipfs://x"))
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT + 39);
+
+		// 缩短描述后退还多出的押金
+		assert_ok!(PoeModule::set_claim_metadata(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			Some(metadata(b"text/plain", b"", b"ipfs://x"))
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT + 34);
+
+		assert_ok!(PoeModule::set_claim_metadata(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			None
+		));
+		System::assert_has_event(
+			Event::<Test>::MetadataCleared { who: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_eq!(PoeModule::claim_metadata(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT);
+	})
+}
+
+#[test]
+fn metadata_deposit_follows_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim_with_metadata(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			metadata(b"text/plain", b"hello", b"ipfs://x")
+		));
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CLAIM_DEPOSIT + 39);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_eq!(PoeModule::claim_metadata(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100 + CLAIM_DEPOSIT + 39);
+	})
+}
+
+#[test]
+fn transfers_are_recorded_in_claim_history() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert!(PoeModule::claim_history(claim.to_vec()).is_empty());
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		System::set_block_number(3);
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			ACCOUNT_ONE,
+			claim.clone(),
+			None
+		));
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+
+		assert_eq!(
+			PoeModule::claim_history(claim.to_vec()),
+			vec![
+				OwnershipRecord { owner: ACCOUNT_ONE, block: 2, kind: TransferKind::Direct },
+				OwnershipRecord { owner: ACCOUNT_TWO, block: 3, kind: TransferKind::Accepted },
+			]
+		);
+		// 创建时的区块高度不随转移改变, 变更时间为最后一次转移
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(ClaimInfo {
+				owner: ACCOUNT_ONE,
+				created_at: 1,
+				updated_at: 3,
+				deposit: CLAIM_DEPOSIT
+			})
+		);
+
+		// 记录已满时丢弃最早的一条
+		System::set_block_number(4);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		assert_eq!(
+			PoeModule::claim_history(claim.to_vec()),
+			vec![
+				OwnershipRecord { owner: ACCOUNT_TWO, block: 3, kind: TransferKind::Accepted },
+				OwnershipRecord { owner: ACCOUNT_ONE, block: 4, kind: TransferKind::Direct },
+			]
+		);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert!(!ClaimHistory::<Test>::contains_key(&claim));
+	})
+}
+
+#[test]
+fn operator_can_revoke_and_transfer_for_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claims(vec![vec![0], vec![1]])
+		));
+		assert_noop!(
+			PoeModule::revoke_claim(
+				RuntimeOrigin::signed(ACCOUNT_POOR),
+				BoundedVec::try_from(vec![0]).unwrap()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::approve_operator(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_POOR,
+			None
+		));
+		System::assert_has_event(
+			Event::<Test>::OperatorApproved {
+				owner: ACCOUNT_ONE,
+				operator: ACCOUNT_POOR,
+				claim: None,
+			}
+			.into(),
+		);
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			BoundedVec::try_from(vec![0]).unwrap()
+		));
+		// 押金退还给拥有者而不是操作员
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 11);
+		assert_eq!(Balances::free_balance(ACCOUNT_POOR), 5);
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			ACCOUNT_TWO,
+			BoundedVec::try_from(vec![1]).unwrap()
+		));
+		System::assert_has_event(
+			Event::<Test>::ClaimTransferred {
+				from: ACCOUNT_ONE,
+				to: ACCOUNT_TWO,
+				claim: BoundedVec::try_from(vec![1]).unwrap(),
+			}
+			.into(),
+		);
+		// 拥有者的操作员不能管理新拥有者的存证
+		assert_noop!(
+			PoeModule::revoke_claim(
+				RuntimeOrigin::signed(ACCOUNT_POOR),
+				BoundedVec::try_from(vec![1]).unwrap()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::revoke_operator(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_POOR,
+			None
+		));
+		assert!(PoeModule::operators(ACCOUNT_ONE).is_empty());
+		assert_noop!(
+			PoeModule::revoke_operator(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, None),
+			Error::<Test>::OperatorNotExist
+		);
+	})
+}
+
+#[test]
+fn claim_operator_is_scoped_to_one_claim() {
+	new_test_ext().execute_with(|| {
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0]).unwrap();
+		let other: ClaimOf<Test> = BoundedVec::try_from(vec![1]).unwrap();
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claims(vec![vec![0], vec![1]])
+		));
+		assert_noop!(
+			PoeModule::approve_operator(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				ACCOUNT_POOR,
+				Some(claim.clone())
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::approve_operator(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_POOR,
+			Some(claim.clone())
+		));
+
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_POOR), other),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			ACCOUNT_TWO,
+			claim.clone(),
+			None
+		));
+		assert_eq!(PoeModule::pending_transfers(&claim).unwrap().from, ACCOUNT_ONE);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+
+		// 存证转移后授权失效
+		assert!(PoeModule::claim_operators(&claim).is_empty());
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_POOR), claim),
+			Error::<Test>::NotClaimOwner
+		);
+	})
+}
+
+#[test]
+fn approve_operator_failed_when_duplicated_or_too_many() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::approve_operator(RuntimeOrigin::signed(ACCOUNT_ONE), 10, None));
+		assert_noop!(
+			PoeModule::approve_operator(RuntimeOrigin::signed(ACCOUNT_ONE), 10, None),
+			Error::<Test>::OperatorAlreadyApproved
+		);
+		assert_ok!(PoeModule::approve_operator(RuntimeOrigin::signed(ACCOUNT_ONE), 11, None));
+		assert_noop!(
+			PoeModule::approve_operator(RuntimeOrigin::signed(ACCOUNT_ONE), 12, None),
+			Error::<Test>::TooManyOperators
+		);
+	})
+}
+
+#[test]
+fn force_create_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::force_create_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_POOR,
+				claim.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// 余额不足以支付押金的账户也可以被强制赋予存证
+		assert_ok!(PoeModule::force_create_claim(
+			RuntimeOrigin::root(),
+			ACCOUNT_POOR,
+			claim.clone()
+		));
+		System::assert_has_event(
+			Event::<Test>::ClaimForceCreated { owner: ACCOUNT_POOR, claim: claim.clone() }.into(),
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_POOR, 1, 0))
+		);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_POOR), 1);
+		assert_noop!(
+			PoeModule::force_create_claim(RuntimeOrigin::root(), ACCOUNT_ONE, claim),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+#[test]
+fn force_create_claim_failed_with_pending_cosign() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::propose_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			signers(vec![ACCOUNT_TWO]),
+			5
+		));
+		assert_noop!(
+			PoeModule::force_create_claim(RuntimeOrigin::root(), ACCOUNT_POOR, claim.clone()),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		// 过期的提案被清除, 押金退还给发起者
+		System::set_block_number(6);
+		assert_ok!(PoeModule::force_create_claim(
+			RuntimeOrigin::root(),
+			ACCOUNT_POOR,
+			claim.clone()
+		));
+		assert_eq!(PoeModule::pending_cosigns(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_noop!(
+			PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim),
+			Error::<Test>::CosignNotExist
+		);
+	})
+}
+
+#[test]
+fn force_revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim.clone()),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_noop!(
+			PoeModule::force_revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim.clone()));
+		System::assert_has_event(
+			Event::<Test>::ClaimForceRevoked { owner: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_eq!(Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+	})
+}
+
+#[test]
+fn force_transfer_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AllowDirectTransfer::set(false);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+
+		assert_ok!(PoeModule::force_transfer_claim(
+			RuntimeOrigin::root(),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		System::assert_has_event(
+			Event::<Test>::ClaimForceTransferred {
+				from: ACCOUNT_ONE,
+				to: ACCOUNT_TWO,
+				claim: claim.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_TWO, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CLAIM_DEPOSIT);
+		assert_eq!(
+			PoeModule::claim_history(claim.to_vec()),
+			vec![OwnershipRecord { owner: ACCOUNT_ONE, block: 1, kind: TransferKind::Forced }]
+		);
+	})
+}
+
+fn genesis_ext(claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	GenesisConfig::<Test> { claims }.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
+
+#[test]
+fn genesis_config_seeds_claims() {
+	genesis_ext(vec![(vec![0, 1], ACCOUNT_ONE), (vec![2], ACCOUNT_ONE), (vec![3], ACCOUNT_TWO)])
+		.execute_with(|| {
+			assert_eq!(
+				Proofs::<Test>::get(DEFAULT_NAMESPACE, BoundedVec::try_from(vec![0, 1]).unwrap()),
+				Some(claim_info(ACCOUNT_ONE, 0, 0))
+			);
+			assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 2);
+			assert_eq!(PoeModule::claim_count(ACCOUNT_TWO), 1);
+			assert!(PoeModule::verify_claim(DEFAULT_NAMESPACE, vec![3], &ACCOUNT_TWO));
+		})
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis claim")]
+fn genesis_config_rejects_duplicate_claims() {
+	genesis_ext(vec![(vec![0, 1], ACCOUNT_ONE), (vec![0, 1], ACCOUNT_TWO)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than MaxClaimLength")]
+fn genesis_config_rejects_too_long_claims() {
+	genesis_ext(vec![(vec![0; 513], ACCOUNT_ONE)]);
+}
+
+fn signers(accounts: Vec<u64>) -> SignersOf<Test> {
+	BoundedVec::try_from(accounts).unwrap()
+}
+
+#[test]
+fn cosigned_claim_is_created_when_all_signed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::propose_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			signers(vec![ACCOUNT_ONE, ACCOUNT_TWO]),
+			10
+		));
+		System::assert_has_event(
+			Event::<Test>::CosignProposed {
+				creator: ACCOUNT_ONE,
+				claim: claim.clone(),
+				deadline: 10,
+			}
+			.into(),
+		);
+		// 押金包括签署人列表: 10 + 2 + 1 + 2 * 8
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 29);
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		assert_ok!(PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim), None);
+		assert_noop!(
+			PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::AlreadyCosigned
+		);
+		assert_noop!(
+			PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_POOR), claim.clone()),
+			Error::<Test>::NotCosigner
+		);
+
+		assert_ok!(PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		System::assert_has_event(
+			Event::<Test>::CosignedClaimCreated {
+				creator: ACCOUNT_ONE,
+				claim: claim.clone(),
+				signers: signers(vec![ACCOUNT_ONE, ACCOUNT_TWO]),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_ONE, 1, 29))
+		);
+		assert_eq!(PoeModule::claim_signers(&claim), Some(signers(vec![ACCOUNT_ONE, ACCOUNT_TWO])));
+		assert_eq!(PoeModule::pending_cosigns(&claim), None);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_eq!(PoeModule::claim_signers(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+	})
+}
+
+#[test]
+fn propose_cosigned_claim_failed_with_invalid_signers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_noop!(
+			PoeModule::propose_cosigned_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				signers(vec![]),
+				10
+			),
+			Error::<Test>::NoSigners
+		);
+		assert_noop!(
+			PoeModule::propose_cosigned_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				signers(vec![ACCOUNT_TWO, ACCOUNT_POOR, ACCOUNT_TWO]),
+				10
+			),
+			Error::<Test>::DuplicateSigner
+		);
+		assert_noop!(
+			PoeModule::propose_cosigned_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				signers(vec![ACCOUNT_TWO]),
+				1
+			),
+			Error::<Test>::InvalidExpiry
+		);
+		// 签署期限不能超过 MaxCosignPeriod
+		assert_noop!(
+			PoeModule::propose_cosigned_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				signers(vec![ACCOUNT_TWO]),
+				22
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	})
+}
+
+#[test]
+fn cosign_claim_failed_after_deadline_and_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::propose_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			signers(vec![ACCOUNT_TWO]),
+			5
+		));
+		assert_noop!(
+			PoeModule::cancel_cosigned_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::NotCosignCreator
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::CosignExpired
+		);
+		// 过期后任何人都可以清除, 押金退还给发起者
+		assert_ok!(PoeModule::cancel_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			claim.clone()
+		));
+		System::assert_has_event(
+			Event::<Test>::CosignCancelled { creator: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_noop!(
+			PoeModule::cosign_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim),
+			Error::<Test>::CosignNotExist
+		);
+	})
+}
+
+#[test]
+fn expired_cosign_proposal_does_not_block_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::propose_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			signers(vec![ACCOUNT_POOR]),
+			5
+		));
+
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		System::set_block_number(6);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		System::assert_has_event(
+			Event::<Test>::CosignCancelled { creator: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert_eq!(PoeModule::pending_cosigns(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_TWO, 6, CLAIM_DEPOSIT))
+		);
+	})
+}
+
+const ATTESTER: u64 = 7;
+
+fn attestation(
+	nonce: u64,
+	claim: Vec<u8>,
+) -> (AttestationPayload<u64, ClaimOf<Test>>, TestSignature) {
+	let payload = AttestationPayload {
+		attester: ATTESTER,
+		claim: BoundedVec::try_from(claim).unwrap(),
+		nonce,
+	};
+	let signature = TestSignature(ATTESTER, PoeModule::attestation_message(&payload));
+	(payload, signature)
+}
+
+#[test]
+fn create_claim_attested_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PoeModule::register_attester(RuntimeOrigin::signed(ACCOUNT_ONE), ATTESTER),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::register_attester(RuntimeOrigin::root(), ATTESTER));
+		assert_noop!(
+			PoeModule::register_attester(RuntimeOrigin::root(), ATTESTER),
+			Error::<Test>::AttesterAlreadyRegistered
+		);
+
+		let (payload, signature) = attestation(0, vec![0, 1]);
+		assert_noop!(
+			PoeModule::create_claim_attested(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				payload.clone(),
+				signature.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::create_claim_attested(
+			RuntimeOrigin::none(),
+			payload.clone(),
+			signature.clone()
+		));
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &payload.claim),
+			Some(claim_info(ATTESTER, 1, 0))
+		);
+		assert_eq!(PoeModule::attester_nonce(ATTESTER), 1);
+		System::assert_last_event(
+			Event::<Test>::ClaimAttested { attester: ATTESTER, claim: payload.claim.clone() }
+				.into(),
+		);
+
+		// 同一签名不能重放
+		assert_noop!(
+			PoeModule::create_claim_attested(RuntimeOrigin::none(), payload, signature),
+			Error::<Test>::StaleAttestation
+		);
+		let (payload, _) = attestation(1, vec![0, 2]);
+		assert_noop!(
+			PoeModule::create_claim_attested(
+				RuntimeOrigin::none(),
+				payload,
+				TestSignature(ATTESTER, vec![])
+			),
+			Error::<Test>::BadAttestation
+		);
+	})
+}
+
+#[test]
+fn validate_unsigned_checks_attestation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let validate = |nonce, claim: Vec<u8>| {
+			let (payload, signature) = attestation(nonce, claim);
+			let call = Call::create_claim_attested { payload, signature };
+			PoeModule::validate_unsigned(TransactionSource::External, &call)
+		};
+		assert_eq!(validate(0, vec![0, 1]), InvalidTransaction::BadSigner.into());
+
+		assert_ok!(PoeModule::register_attester(RuntimeOrigin::root(), ATTESTER));
+		let valid = validate(0, vec![0, 1]).unwrap();
+		assert_eq!(valid.priority, 100);
+		assert!(valid.requires.is_empty());
+		// 未来的序号依赖前一个序号
+		assert_eq!(validate(2, vec![0, 1]).unwrap().requires.len(), 1);
+
+		let (payload, _) = attestation(0, vec![0, 1]);
+		let call =
+			Call::create_claim_attested { payload, signature: TestSignature(ACCOUNT_ONE, vec![]) };
+		assert_eq!(
+			PoeModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadProof.into()
+		);
+
+		let (payload, signature) = attestation(0, vec![0, 1]);
+		assert_ok!(PoeModule::create_claim_attested(RuntimeOrigin::none(), payload, signature));
+		assert_eq!(validate(0, vec![0, 2]), InvalidTransaction::Stale.into());
+		assert_eq!(validate(1, vec![0, 1]), InvalidTransaction::Call.into());
+	})
+}
+
+#[test]
+fn remove_attester_keeps_nonce() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PoeModule::remove_attester(RuntimeOrigin::root(), ATTESTER),
+			Error::<Test>::NotAttester
+		);
+		assert_ok!(PoeModule::register_attester(RuntimeOrigin::root(), ATTESTER));
+		let (payload, signature) = attestation(0, vec![0, 1]);
+		assert_ok!(PoeModule::create_claim_attested(RuntimeOrigin::none(), payload, signature));
+
+		assert_ok!(PoeModule::remove_attester(RuntimeOrigin::root(), ATTESTER));
+		System::assert_last_event(Event::<Test>::AttesterRemoved { attester: ATTESTER }.into());
+		let (payload, signature) = attestation(1, vec![0, 2]);
+		assert_noop!(
+			PoeModule::create_claim_attested(RuntimeOrigin::none(), payload, signature),
+			Error::<Test>::NotAttester
+		);
+
+		// 已提交的存证不受影响, 重新登记后序号继续递增
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &BoundedVec::try_from(vec![0, 1]).unwrap()),
+			Some(claim_info(ATTESTER, 1, 0))
+		);
+		assert_ok!(PoeModule::register_attester(RuntimeOrigin::root(), ATTESTER));
+		assert_eq!(PoeModule::attester_nonce(ATTESTER), 1);
+	})
+}
+
+#[test]
+fn migrations_work() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		let legacy: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let deposited: ClaimOf<Test> = BoundedVec::try_from(vec![0, 2]).unwrap();
+		// 最初的 `(拥有者, 区块高度)` 和之后追加了押金的元组
+		unhashed::put(
+			&migrations::v1::Proofs::<Test>::hashed_key_for(&legacy),
+			&(ACCOUNT_ONE, 3u64),
+		);
+		unhashed::put(
+			&migrations::v1::Proofs::<Test>::hashed_key_for(&deposited),
+			&(ACCOUNT_TWO, 4u64, 12u64),
+		);
+		// 追加押金的同时已开始维护拥有者索引
+		ClaimsByOwner::<Test>::insert(ACCOUNT_TWO, &deposited, ());
+		ClaimCount::<Test>::insert(ACCOUNT_TWO, 1);
+
+		// v2 的迁移只处理 v1 的存储
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::on_chain_storage_version(), 0);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			migrations::v1::Proofs::<Test>::get(&legacy),
+			Some(migrations::v1::ClaimInfo { owner: ACCOUNT_ONE, created_at: 3, deposit: 0 })
+		);
+		assert_eq!(
+			migrations::v1::Proofs::<Test>::get(&deposited),
+			Some(migrations::v1::ClaimInfo { owner: ACCOUNT_TWO, created_at: 4, deposit: 12 })
+		);
+		// 缺少索引的存证被补上, 已有索引的不重复计数
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_ONE, 0, 10), vec![legacy.clone()]);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 1);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_TWO, 0, 10), vec![deposited.clone()]);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_TWO), 1);
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(
+			migrations::v2::Proofs::<Test>::get(&legacy),
+			Some(claim_info(ACCOUNT_ONE, 3, 0))
+		);
+		assert_eq!(
+			migrations::v2::Proofs::<Test>::get(&deposited),
+			Some(claim_info(ACCOUNT_TWO, 4, 12))
+		);
+
+		migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::on_chain_storage_version(), 3);
+		assert_eq!(migrations::v2::Proofs::<Test>::iter_keys().count(), 0);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &legacy),
+			Some(claim_info(ACCOUNT_ONE, 3, 0))
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &deposited),
+			Some(claim_info(ACCOUNT_TWO, 4, 12))
+		);
+
+		// 已经迁移过的存储不会被再次转换
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &legacy),
+			Some(claim_info(ACCOUNT_ONE, 3, 0))
+		);
+	})
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migration_checks_pass_when_migrations_are_skipped() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<PoeModule>();
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim));
+
+		let state = migrations::v1::MigrateToV1::<Test>::pre_upgrade().unwrap();
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_ok!(migrations::v1::MigrateToV1::<Test>::post_upgrade(state));
+		let state = migrations::v2::MigrateToV2::<Test>::pre_upgrade().unwrap();
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_ok!(migrations::v2::MigrateToV2::<Test>::post_upgrade(state));
+		let state = migrations::v3::MigrateToV3::<Test>::pre_upgrade().unwrap();
+		migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_ok!(migrations::v3::MigrateToV3::<Test>::post_upgrade(state));
+	})
+}
+
+#[test]
+fn supersede_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let v1: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let v2: ClaimOf<Test> = BoundedVec::try_from(vec![0, 2]).unwrap();
+		let v3: ClaimOf<Test> = BoundedVec::try_from(vec![0, 3]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v1.clone()));
+
+		assert_noop!(
+			PoeModule::supersede_claim(RuntimeOrigin::signed(ACCOUNT_TWO), v1.clone(), v2.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v1.clone(), v1.clone()),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			v1.clone(),
+			v2.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::ClaimSuperseded { who: ACCOUNT_ONE, old: v1.clone(), new: v2.clone() }
+				.into(),
+		);
+		// 旧版本保留, 新版本单独锁定押金
+		assert!(Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &v1));
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &v2),
+			Some(claim_info(ACCOUNT_ONE, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 2 * CLAIM_DEPOSIT);
+		assert_eq!(
+			PoeModule::claim_details(DEFAULT_NAMESPACE, v1.to_vec()).unwrap().superseded_by,
+			Some(v2.to_vec())
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v1.clone(), v3.clone()),
+			Error::<Test>::AlreadySuperseded
+		);
+
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			v2.clone(),
+			v3.clone()
+		));
+		let chain = vec![v1.to_vec(), v2.to_vec(), v3.to_vec()];
+		assert_eq!(PoeModule::claim_versions(v1.to_vec()), chain);
+		assert_eq!(PoeModule::claim_versions(v3.to_vec()), chain);
+		assert!(PoeModule::claim_versions(vec![9]).is_empty());
+	})
+}
+
+#[test]
+fn revoking_a_version_relinks_the_chain() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let v1: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let v2: ClaimOf<Test> = BoundedVec::try_from(vec![0, 2]).unwrap();
+		let v3: ClaimOf<Test> = BoundedVec::try_from(vec![0, 3]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v1.clone()));
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			v1.clone(),
+			v2.clone()
+		));
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			v2.clone(),
+			v3.clone()
+		));
+
+		// 撤销中间版本后前后版本直接相连
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v2.clone()));
+		assert_eq!(PoeModule::superseded_by(&v1), Some(v3.clone()));
+		assert_eq!(PoeModule::supersedes(&v3), Some(v1.clone()));
+		assert_eq!(PoeModule::claim_versions(v3.to_vec()), vec![v1.to_vec(), v3.to_vec()]);
+
+		// 撤销最新版本后前一个版本重新成为最新版本
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), v3.clone()));
+		assert_eq!(PoeModule::superseded_by(&v1), None);
+		assert_eq!(PoeModule::claim_versions(v1.to_vec()), vec![v1.to_vec()]);
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			v1.clone(),
+			v3.clone()
+		));
+	})
+}
+
+// CommitmentDeposit
+const COMMITMENT_DEPOSIT: u64 = 3;
+
+#[test]
+fn commit_and_reveal_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = [7u8; 32];
+		let commitment = PoeModule::commitment_of(&claim, &salt);
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), commitment));
+		System::assert_last_event(
+			Event::<Test>::ClaimCommitted { who: ACCOUNT_ONE, commitment, expires_at: 11 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), COMMITMENT_DEPOSIT);
+		assert_eq!(PoeModule::commitment_count(ACCOUNT_ONE), 1);
+		assert_noop!(
+			PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_TWO), commitment),
+			Error::<Test>::CommitmentAlreadyExist
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), [0u8; 32]),
+			Error::<Test>::CommitmentNotExist
+		);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone(), salt),
+			Error::<Test>::NotCommitter
+		);
+		assert_ok!(PoeModule::reveal_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			salt
+		));
+		System::assert_last_event(
+			Event::<Test>::ClaimRevealed {
+				who: ACCOUNT_ONE,
+				claim: claim.clone(),
+				committed_at: 1,
+			}
+			.into(),
+		);
+
+		// 存证的创建时间为承诺时的区块高度
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_ONE, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(PoeModule::commitments(commitment), None);
+		assert!(!CommitmentQueue::<Test>::contains_key(11));
+		assert_eq!(PoeModule::commitment_count(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT);
+	})
+}
+
+#[test]
+fn reveal_claim_preempts_later_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = [7u8; 32];
+		let commitment = PoeModule::commitment_of(&claim, &salt);
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), commitment));
+
+		// 看到公开交易后抢先创建同名存证
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_ok!(PoeModule::reveal_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			salt
+		));
+		System::assert_has_event(
+			Event::<Test>::ClaimPreempted { owner: ACCOUNT_TWO, claim: claim.clone() }.into(),
+		);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_ONE, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_TWO), 0);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_ONE, 0, 10), vec![claim]);
+	})
+}
+
+#[test]
+fn reveal_claim_cancels_later_cosign_proposal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = [7u8; 32];
+		let commitment = PoeModule::commitment_of(&claim, &salt);
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), commitment));
+
+		System::set_block_number(5);
+		assert_ok!(PoeModule::propose_cosigned_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			claim.clone(),
+			signers(vec![ACCOUNT_ONE, ACCOUNT_TWO]),
+			10
+		));
+		assert_ok!(PoeModule::reveal_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			salt
+		));
+		System::assert_has_event(
+			Event::<Test>::CosignCancelled { creator: ACCOUNT_TWO, claim: claim.clone() }.into(),
+		);
+		assert_eq!(PoeModule::pending_cosigns(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_ONE, 1, CLAIM_DEPOSIT))
+		);
+	})
+}
+
+#[test]
+fn reveal_claim_does_not_preempt_locked_or_challenged_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let locked: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let challenged: ClaimOf<Test> = BoundedVec::try_from(vec![0, 2]).unwrap();
+		let salt = [7u8; 32];
+		assert_ok!(PoeModule::commit_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			PoeModule::commitment_of(&locked, &salt)
+		));
+		assert_ok!(PoeModule::commit_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			PoeModule::commitment_of(&challenged, &salt)
+		));
+
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), locked.clone()));
+		assert_ok!(PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_TWO), locked.clone(), None));
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_ONE), locked.clone(), salt),
+			Error::<Test>::ClaimLocked
+		);
+
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), challenged.clone()));
+		assert_ok!(PoeModule::challenge_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			challenged.clone()
+		));
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_POOR), challenged.clone(), salt),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_TWO, 0, 10).len(), 2);
+	})
+}
+
+#[test]
+fn reveal_claim_failed_when_claim_created_before_commitment() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+
+		let salt = [7u8; 32];
+		let commitment = PoeModule::commitment_of(&claim, &salt);
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), commitment));
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim, salt),
+			Error::<Test>::ProofAlreadyExist
+		);
+	})
+}
+
+#[test]
+fn unrevealed_commitment_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		let salt = [7u8; 32];
+		let commitment = PoeModule::commitment_of(&claim, &salt);
+		assert_ok!(PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), commitment));
+
+		PoeModule::on_initialize(10);
+		assert!(PoeModule::commitments(commitment).is_some());
+
+		PoeModule::on_initialize(11);
+		assert_eq!(PoeModule::commitments(commitment), None);
+		assert!(!CommitmentQueue::<Test>::contains_key(11));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(PoeModule::commitment_count(ACCOUNT_ONE), 0);
+		System::assert_last_event(
+			Event::<Test>::CommitmentExpired { who: ACCOUNT_ONE, commitment }.into(),
+		);
+		assert_noop!(
+			PoeModule::reveal_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim, salt),
+			Error::<Test>::CommitmentNotExist
+		);
+	})
+}
+
+#[test]
+fn commit_claim_failed_with_too_many_commitments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::commit_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			BlakeTwo256::hash(&[0])
+		));
+		// 单个账户不能占满一个区块的承诺队列
+		assert_noop!(
+			PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_ONE), BlakeTwo256::hash(&[1])),
+			Error::<Test>::TooManyPendingCommitments
+		);
+		assert_ok!(PoeModule::commit_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			BlakeTwo256::hash(&[1])
+		));
+		assert_noop!(
+			PoeModule::commit_claim(RuntimeOrigin::signed(ACCOUNT_POOR), BlakeTwo256::hash(&[2])),
+			Error::<Test>::TooManyCommitments
+		);
+	})
+}
+
+#[test]
+fn namespace_permissions_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), false));
+		System::assert_last_event(
+			Event::<Test>::NamespaceCreated { owner: ACCOUNT_ONE, namespace: 1, open: false }
+				.into(),
+		);
+		assert_eq!(
+			PoeModule::namespaces(1),
+			Some(NamespaceInfo { owner: ACCOUNT_ONE, open: false, deposit: 20 })
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 20);
+
+		// 默认命名空间不能通过命名空间的接口操作
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				DEFAULT_NAMESPACE,
+				claim.clone()
+			),
+			Error::<Test>::NamespaceNotExist
+		);
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				1,
+				claim.clone()
+			),
+			Error::<Test>::NoNamespacePermission
+		);
+		assert_noop!(
+			PoeModule::add_namespace_writer(RuntimeOrigin::signed(ACCOUNT_TWO), 1, ACCOUNT_TWO),
+			Error::<Test>::NotNamespaceOwner
+		);
+
+		assert_ok!(PoeModule::add_namespace_writer(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			ACCOUNT_TWO
+		));
+		assert_noop!(
+			PoeModule::add_namespace_writer(RuntimeOrigin::signed(ACCOUNT_ONE), 1, ACCOUNT_TWO),
+			Error::<Test>::WriterAlreadyAdded
+		);
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			1,
+			claim.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::NamespacedClaimCreated { who: ACCOUNT_TWO, namespace: 1, claim }.into(),
+		);
+
+		assert_ok!(PoeModule::remove_namespace_writer(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			ACCOUNT_TWO
+		));
+		assert_noop!(
+			PoeModule::remove_namespace_writer(RuntimeOrigin::signed(ACCOUNT_ONE), 1, ACCOUNT_TWO),
+			Error::<Test>::WriterNotExist
+		);
+		let other: ClaimOf<Test> = BoundedVec::try_from(vec![0, 2]).unwrap();
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				1,
+				other.clone()
+			),
+			Error::<Test>::NoNamespacePermission
+		);
+
+		// 开放后任何账户都可以创建存证
+		assert_ok!(PoeModule::set_namespace_open(RuntimeOrigin::signed(ACCOUNT_ONE), 1, true));
+		System::assert_last_event(
+			Event::<Test>::NamespaceOpenSet { namespace: 1, open: true }.into(),
+		);
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			1,
+			other
+		));
+	})
+}
+
+#[test]
+fn namespaced_claims_do_not_collide() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), true));
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_TWO), true));
+
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			claim.clone()
+		));
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			2,
+			claim.clone()
+		));
+		assert_noop!(
+			PoeModule::create_namespaced_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				1,
+				claim.clone()
+			),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_eq!(
+			PoeModule::namespaced_claim(2, claim.to_vec()),
+			Some(claim_info(ACCOUNT_TWO, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(PoeModule::namespace_claims(1, 0, 10), vec![claim.clone()]);
+		// 命名空间中的存证单独计数, 不在默认命名空间的索引中
+		assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 1);
+		assert_eq!(PoeModule::namespaced_claim_count(ACCOUNT_ONE), 1);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_ONE, 0, 10), vec![claim.clone()]);
+
+		assert_noop!(
+			PoeModule::transfer_namespaced_claim(
+				RuntimeOrigin::signed(ACCOUNT_TWO),
+				1,
+				ACCOUNT_TWO,
+				claim.clone()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		// 默认命名空间中同名存证的锁定不影响其他命名空间
+		assert_ok!(PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), None));
+		assert_ok!(PoeModule::transfer_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::NamespacedClaimTransferred {
+				from: ACCOUNT_ONE,
+				to: ACCOUNT_TWO,
+				namespace: 1,
+				claim: claim.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(PoeModule::namespaced_claim(1, claim.to_vec()).unwrap().owner, ACCOUNT_TWO);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_ONE), 1);
+		assert_eq!(PoeModule::namespaced_claim_count(ACCOUNT_ONE), 0);
+		assert_eq!(PoeModule::claim_count(ACCOUNT_TWO), 0);
+		assert_eq!(PoeModule::namespaced_claim_count(ACCOUNT_TWO), 2);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 20 + 2 * CLAIM_DEPOSIT);
+
+		assert_ok!(PoeModule::revoke_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_TWO),
+			1,
+			claim.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::NamespacedClaimRevoked {
+				who: ACCOUNT_TWO,
+				namespace: 1,
+				claim: claim.clone(),
+			}
+			.into(),
+		);
+		assert!(PoeModule::namespace_claims(1, 0, 10).is_empty());
+		assert!(Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert!(Proofs::<Test>::contains_key(2, &claim));
+		assert_eq!(PoeModule::namespaced_claim_count(ACCOUNT_TWO), 1);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 20 + CLAIM_DEPOSIT);
+	})
+}
+
+#[test]
+fn namespaced_claim_details_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), true));
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			claim.clone()
+		));
+
+		assert_eq!(
+			PoeModule::claim_details(1, claim.to_vec()),
+			Some(ClaimDetails {
+				owner: ACCOUNT_ONE,
+				created_at: 3,
+				updated_at: 3,
+				deposit: CLAIM_DEPOSIT,
+				expires_at: None,
+				metadata: None,
+				superseded_by: None,
+				lock: None
+			})
+		);
+		assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()), None);
+		assert!(PoeModule::verify_claim(1, claim.to_vec(), &ACCOUNT_ONE));
+		assert!(!PoeModule::verify_claim(DEFAULT_NAMESPACE, claim.to_vec(), &ACCOUNT_ONE));
+
+		// 附加功能只作用于默认命名空间中的存证
+		assert_noop!(
+			PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), None),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim),
+			Error::<Test>::ClaimNotExist
+		);
+	})
+}
+
+#[test]
+fn remove_namespace_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), false));
+		assert_ok!(PoeModule::add_namespace_writer(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			ACCOUNT_TWO
+		));
+		assert_ok!(PoeModule::create_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			claim.clone()
+		));
+
+		assert_noop!(
+			PoeModule::remove_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), 2),
+			Error::<Test>::NamespaceNotExist
+		);
+		assert_noop!(
+			PoeModule::remove_namespace(RuntimeOrigin::signed(ACCOUNT_TWO), 1),
+			Error::<Test>::NotNamespaceOwner
+		);
+		assert_noop!(
+			PoeModule::remove_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), 1),
+			Error::<Test>::NamespaceNotEmpty
+		);
+		assert_ok!(PoeModule::revoke_namespaced_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			claim
+		));
+		// 写入者也需要先移除
+		assert_noop!(
+			PoeModule::remove_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), 1),
+			Error::<Test>::NamespaceNotEmpty
+		);
+		assert_ok!(PoeModule::remove_namespace_writer(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			1,
+			ACCOUNT_TWO
+		));
+
+		assert_ok!(PoeModule::remove_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), 1));
+		System::assert_last_event(
+			Event::<Test>::NamespaceRemoved { owner: ACCOUNT_ONE, namespace: 1 }.into(),
+		);
+		assert_eq!(PoeModule::namespaces(1), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100);
+
+		// 编号不会被重新分配
+		assert_ok!(PoeModule::create_namespace(RuntimeOrigin::signed(ACCOUNT_ONE), false));
+		assert_eq!(PoeModule::namespaces(1), None);
+		assert_eq!(PoeModule::namespaces(2).map(|info| info.owner), Some(ACCOUNT_ONE));
+	})
+}
+
+// ChallengeBond
+const CHALLENGE_BOND: u64 = 15;
+
+#[test]
+fn challenged_claim_is_frozen_until_dismissed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()),
+			Error::<Test>::ChallengeOwnClaim
+		);
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		System::assert_last_event(
+			Event::<Test>::ClaimChallenged { challenger: ACCOUNT_TWO, claim: claim.clone() }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CHALLENGE_BOND);
+		assert_noop!(
+			PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::AlreadyChallenged
+		);
+
+		// 裁决前存证不能撤销或转移
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claim.clone()
+			),
+			Error::<Test>::ClaimFrozen
+		);
+
+		assert_noop!(
+			PoeModule::resolve_challenge(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				claim.clone(),
+				ChallengeVerdict::Dismissed
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::resolve_challenge(
+			RuntimeOrigin::root(),
+			claim.clone(),
+			ChallengeVerdict::Dismissed
+		));
+		System::assert_last_event(
+			Event::<Test>::ChallengeDismissed {
+				challenger: ACCOUNT_TWO,
+				claim: claim.clone(),
+				slashed: CHALLENGE_BOND,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100 - CHALLENGE_BOND);
+		assert_eq!(PoeModule::challenges(&claim), None);
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim));
+	})
+}
+
+#[test]
+fn upheld_challenge_transfers_claim_to_challenger() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::resolve_challenge(
+			RuntimeOrigin::root(),
+			claim.clone(),
+			ChallengeVerdict::Upheld
+		));
+		System::assert_last_event(
+			Event::<Test>::ChallengeUpheld {
+				challenger: ACCOUNT_TWO,
+				owner: ACCOUNT_ONE,
+				claim: claim.clone(),
+				slashed: CLAIM_DEPOSIT,
+			}
+			.into(),
+		);
+
+		// 拥有者的押金被罚没, 挑战者取回保证金并以零押金持有存证
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(ClaimInfo { owner: ACCOUNT_TWO, created_at: 1, updated_at: 2, deposit: 0 })
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100 - CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100);
+		assert_eq!(
+			PoeModule::claim_history(claim.to_vec()),
+			vec![OwnershipRecord { owner: ACCOUNT_ONE, block: 2, kind: TransferKind::Disputed }]
+		);
+		assert_eq!(PoeModule::claims_of(&ACCOUNT_TWO, 0, 10), vec![claim.clone()]);
+		assert_noop!(
+			PoeModule::resolve_challenge(RuntimeOrigin::root(), claim, ChallengeVerdict::Upheld),
+			Error::<Test>::ChallengeNotExist
+		);
+	})
+}
+
+#[test]
+fn force_revoke_refunds_challenge_bond() {
+	new_test_ext().execute_with(|| {
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+
+		assert_ok!(PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim.clone()));
+		assert_eq!(PoeModule::challenges(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100);
+	})
+}
+
+#[test]
+fn challenged_claim_expires_after_resolution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_expiring_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			10
+		));
+		assert_ok!(PoeModule::challenge_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+
+		// 挑战期间存证和双方的押金都保留到裁决
+		PoeModule::on_initialize(10);
+		assert!(Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert_eq!(PoeModule::claim_expiry(&claim), Some(10));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), CHALLENGE_BOND);
+
+		System::set_block_number(12);
+		assert_ok!(PoeModule::resolve_challenge(
+			RuntimeOrigin::root(),
+			claim.clone(),
+			ChallengeVerdict::Dismissed
+		));
+		System::assert_last_event(
+			Event::<Test>::ClaimExpired { owner: ACCOUNT_ONE, claim: claim.clone() }.into(),
+		);
+		assert!(!Proofs::<Test>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert_eq!(PoeModule::claim_expiry(&claim), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_ONE), 100);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100 - CHALLENGE_BOND);
+	})
+}
+
+#[test]
+fn locked_claim_cannot_be_revoked_or_transferred() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+
+		assert_noop!(
+			PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone(), Some(10)),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), Some(1)),
+			Error::<Test>::InvalidLock
+		);
+		assert_ok!(PoeModule::lock_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			claim.clone(),
+			Some(10)
+		));
+		System::assert_last_event(
+			Event::<Test>::ClaimLocked { who: ACCOUNT_ONE, claim: claim.clone(), until: Some(10) }
+				.into(),
+		);
+		assert_eq!(
+			PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().lock,
+			Some(ClaimLock::Until(10))
+		);
+
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()),
+			Error::<Test>::ClaimLocked
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(
+				RuntimeOrigin::signed(ACCOUNT_ONE),
+				ACCOUNT_TWO,
+				claim.clone()
+			),
+			Error::<Test>::ClaimLocked
+		);
+		assert_ok!(PoeModule::offer_claim(
+			RuntimeOrigin::signed(ACCOUNT_ONE),
+			ACCOUNT_TWO,
+			claim.clone(),
+			None
+		));
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()),
+			Error::<Test>::ClaimLocked
+		);
+		// 锁定只能延长
+		assert_noop!(
+			PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), Some(5)),
+			Error::<Test>::ClaimLocked
+		);
+
+		System::set_block_number(10);
+		assert_eq!(PoeModule::claim_details(DEFAULT_NAMESPACE, claim.to_vec()).unwrap().lock, None);
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_eq!(PoeModule::claim_lock(&claim), None);
+	})
+}
+
+#[test]
+fn permanent_lock_only_yields_to_force_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()));
+		assert_ok!(PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), None));
+		assert_noop!(
+			PoeModule::lock_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone(), Some(100)),
+			Error::<Test>::ClaimLocked
+		);
+
+		System::set_block_number(1_000);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_ONE), claim.clone()),
+			Error::<Test>::ClaimLocked
+		);
+
+		assert_ok!(PoeModule::force_transfer_claim(
+			RuntimeOrigin::root(),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		assert_eq!(PoeModule::claim_lock(&claim), Some(ClaimLock::Forever));
+		assert_ok!(PoeModule::force_revoke_claim(RuntimeOrigin::root(), claim.clone()));
+		assert_eq!(PoeModule::claim_lock(&claim), None);
+	})
+}
+
+#[test]
+fn sponsorship_budget_and_quota_can_be_managed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 50));
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 50);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 50);
+		assert_noop!(
+			PoeModule::withdraw_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 60),
+			Error::<Test>::InsufficientBudget
+		);
+		assert_ok!(PoeModule::withdraw_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 20));
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 30);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 30);
+
+		assert_noop!(
+			PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, 0),
+			Error::<Test>::InvalidQuota
+		);
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, 2));
+		assert_eq!(
+			PoeModule::sponsorships(ACCOUNT_POOR),
+			Some(Sponsorship { sponsor: ACCOUNT_ONE, remaining: 2 })
+		);
+		assert_noop!(
+			PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_TWO), ACCOUNT_POOR, 1),
+			Error::<Test>::AlreadySponsored
+		);
+		assert_noop!(
+			PoeModule::unsponsor_account(RuntimeOrigin::signed(ACCOUNT_TWO), ACCOUNT_POOR),
+			Error::<Test>::NotSponsor
+		);
+
+		// 被代付的账户也可以取消代付
+		assert_ok!(PoeModule::unsponsor_account(RuntimeOrigin::signed(ACCOUNT_POOR), ACCOUNT_POOR));
+		System::assert_last_event(
+			Event::<Test>::AccountUnsponsored { sponsor: ACCOUNT_ONE, who: ACCOUNT_POOR }.into(),
+		);
+		assert_noop!(
+			PoeModule::unsponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR),
+			Error::<Test>::NotSponsored
+		);
+	})
+}
+
+fn poe_call() -> RuntimeCall {
+	RuntimeCall::PoeModule(Call::create_claim { claim: BoundedVec::try_from(vec![0, 1]).unwrap() })
+}
+
+/// 以 `tip` 为小费, 对长度为 `len` 的 `call` 收取并结算手续费
+fn charge_fee(
+	who: u64,
+	call: RuntimeCall,
+	tip: u64,
+	len: usize,
+) -> Result<(), TransactionValidityError> {
+	let info = DispatchInfo::default();
+	let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes };
+	let pre = ChargeSponsoredFee::<Test>::from(tip).pre_dispatch(&who, &call, &info, len)?;
+	ChargeSponsoredFee::<Test>::post_dispatch(Some(pre), &info, &post_info, len, &Ok(()))
+}
+
+#[test]
+fn sponsor_pays_fees_of_poe_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 25));
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, 2));
+
+		// 手续费等于交易长度, 从代付人的预算中扣除
+		assert_ok!(charge_fee(ACCOUNT_POOR, poe_call(), 0, 10));
+		System::assert_last_event(
+			Event::<Test>::FeeSponsored { sponsor: ACCOUNT_ONE, who: ACCOUNT_POOR, fee: 10 }.into(),
+		);
+		assert_eq!(Balances::free_balance(ACCOUNT_POOR), 5);
+		assert_eq!(Balances::free_balance(ACCOUNT_ONE), 75);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 15);
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 15);
+		assert_eq!(PoeModule::sponsorships(ACCOUNT_POOR).map(|s| s.remaining), Some(1));
+
+		// 预算不足时由签名账户自己支付
+		assert_eq!(
+			charge_fee(ACCOUNT_POOR, poe_call(), 0, 20),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		// 用完最后一次代付和全部预算
+		assert_ok!(charge_fee(ACCOUNT_POOR, poe_call(), 0, 15));
+		assert_eq!(PoeModule::sponsorships(ACCOUNT_POOR), None);
+		assert!(!SponsorBudgets::<Test>::contains_key(ACCOUNT_ONE));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_ONE), 75);
+
+		assert_ok!(charge_fee(ACCOUNT_POOR, poe_call(), 0, 4));
+		assert_eq!(Balances::free_balance(ACCOUNT_POOR), 1);
+	})
+}
+
+#[test]
+fn validating_sponsored_call_does_not_use_budget() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 25));
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, 2));
+
+		// 代付人的余额都在预算中, 验证仍然通过
+		let info = DispatchInfo::default();
+		for _ in 0..3 {
+			assert_ok!(ChargeSponsoredFee::<Test>::from(0).validate(
+				&ACCOUNT_POOR,
+				&poe_call(),
+				&info,
+				10
+			));
+		}
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 25);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 25);
+		assert_eq!(PoeModule::sponsorships(ACCOUNT_POOR).map(|s| s.remaining), Some(2));
+		assert_eq!(Balances::free_balance(ACCOUNT_POOR), 5);
+
+		// 预算不足时按签名账户自己支付验证
+		assert_eq!(
+			ChargeSponsoredFee::<Test>::from(0).validate(&ACCOUNT_POOR, &poe_call(), &info, 30),
+			Err(InvalidTransaction::Payment.into())
+		);
+	})
+}
+
+/// 像区块中的交易一样依次执行 `pre_dispatch`, `call` 和 `post_dispatch`
+fn dispatch_with_fee(who: u64, call: RuntimeCall, len: usize) -> DispatchResult {
+	let info = call.get_dispatch_info();
+	let pre = ChargeSponsoredFee::<Test>::from(0)
+		.pre_dispatch(&who, &call, &info, len)
+		.unwrap();
+	let (post_info, result) = match call.dispatch(RuntimeOrigin::signed(who)) {
+		Ok(post_info) => (post_info, Ok(())),
+		Err(e) => (e.post_info, Err(e.error)),
+	};
+	ChargeSponsoredFee::<Test>::post_dispatch(Some(pre), &info, &post_info, len, &result).unwrap();
+	result
+}
+
+#[test]
+fn sponsor_pays_claim_deposit_of_sponsored_call() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 50));
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_POOR, 1));
+
+		// 余额不足押金的账户也能通过代付创建存证
+		assert_ok!(dispatch_with_fee(ACCOUNT_POOR, poe_call(), 10));
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(ACCOUNT_POOR, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(PoeModule::deposit_payer(&claim), Some(ACCOUNT_ONE));
+		assert_eq!(ActiveSponsorship::<Test>::get(), None);
+		assert_eq!(Balances::free_balance(ACCOUNT_POOR), 5);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_POOR), 0);
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 50 - 10 - CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 50 - 10);
+
+		// 押金不随存证转移, 撤销时退回代付人的预算
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ACCOUNT_POOR),
+			ACCOUNT_TWO,
+			claim.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_TWO), 0);
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ACCOUNT_TWO), claim.clone()));
+		assert_eq!(PoeModule::deposit_payer(&claim), None);
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 50 - 10);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ONE), 50 - 10);
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 100);
+
+		// 代付次数用完后押金由签名账户自己锁定
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(ACCOUNT_POOR), claim),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	})
+}
+
+#[test]
+fn sponsored_account_pays_deposit_when_budget_is_short() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 余额只够存证押金, 不够手续费
+		let who = 4;
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ACCOUNT_TWO), who, CLAIM_DEPOSIT + 1));
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 20));
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), who, 1));
+
+		// 支付手续费后剩余的预算不够押金
+		assert_ok!(dispatch_with_fee(who, poe_call(), 10));
+		let claim: ClaimOf<Test> = BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(
+			Proofs::<Test>::get(DEFAULT_NAMESPACE, &claim),
+			Some(claim_info(who, 1, CLAIM_DEPOSIT))
+		);
+		assert_eq!(PoeModule::deposit_payer(&claim), None);
+		assert_eq!(Balances::reserved_balance(who), CLAIM_DEPOSIT);
+		assert_eq!(Balances::free_balance(who), 1);
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 10);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(who), claim));
+		assert_eq!(Balances::free_balance(who), CLAIM_DEPOSIT + 1);
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 10);
+	})
+}
+
+#[test]
+fn sponsor_does_not_pay_tips_or_other_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::fund_sponsorship(RuntimeOrigin::signed(ACCOUNT_ONE), 50));
+		assert_ok!(PoeModule::sponsor_account(RuntimeOrigin::signed(ACCOUNT_ONE), ACCOUNT_TWO, 5));
+
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert_ok!(charge_fee(ACCOUNT_TWO, remark, 0, 10));
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 90);
+
+		assert_ok!(charge_fee(ACCOUNT_TWO, poe_call(), 1, 10));
+		assert_eq!(Balances::free_balance(ACCOUNT_TWO), 79);
+
+		assert_eq!(PoeModule::sponsor_budget(ACCOUNT_ONE), 50);
+		assert_eq!(PoeModule::sponsorships(ACCOUNT_TWO).map(|s| s.remaining), Some(5));
+	})
+}
@@ -0,0 +1,1070 @@
+//! Placeholder weights for pallet_poe
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY A BENCHMARK RUN. The storage reads and writes are counted
+//! from the code paths; the execution times are rough estimates rounded to two significant figures
+//! and the proof sizes are worst cases derived from the storage bounds. Regenerate this file on
+//! reference hardware before relying on it to size blocks:
+//!
+//! ```text
+//! ./target/release/node-template benchmark pallet --chain dev --pallet pallet_poe \
+//!     --extrinsic '*' --steps 50 --repeat 20 --output pallets/poe/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(l: u32, ) -> Weight;
+	fn revoke_claim(l: u32, ) -> Weight;
+	fn transfer_claim(l: u32, ) -> Weight;
+	fn create_claims(n: u32, ) -> Weight;
+	fn revoke_claims(n: u32, ) -> Weight;
+	fn transfer_claims(n: u32, ) -> Weight;
+	fn anchor_root() -> Weight;
+	fn remove_anchor() -> Weight;
+	fn create_expiring_claim(l: u32, ) -> Weight;
+	fn expire_claims(n: u32, ) -> Weight;
+	fn offer_claim(l: u32, ) -> Weight;
+	fn accept_claim(l: u32, ) -> Weight;
+	fn cancel_offer(l: u32, ) -> Weight;
+	fn set_claim_metadata() -> Weight;
+	fn approve_operator() -> Weight;
+	fn revoke_operator() -> Weight;
+	fn propose_cosigned_claim() -> Weight;
+	fn cosign_claim() -> Weight;
+	fn cancel_cosigned_claim() -> Weight;
+	fn create_claim_attested(l: u32, ) -> Weight;
+	fn register_attester() -> Weight;
+	fn remove_attester() -> Weight;
+	fn supersede_claim(l: u32, ) -> Weight;
+	fn commit_claim() -> Weight;
+	fn reveal_claim(l: u32, ) -> Weight;
+	fn expire_commitments(n: u32, ) -> Weight;
+	fn create_namespace() -> Weight;
+	fn set_namespace_open() -> Weight;
+	fn add_namespace_writer() -> Weight;
+	fn remove_namespace_writer() -> Weight;
+	fn create_namespaced_claim(l: u32, ) -> Weight;
+	fn revoke_namespaced_claim(l: u32, ) -> Weight;
+	fn transfer_namespaced_claim(l: u32, ) -> Weight;
+	fn remove_namespace() -> Weight;
+	fn challenge_claim(l: u32, ) -> Weight;
+	fn resolve_challenge(l: u32, ) -> Weight;
+	fn lock_claim(l: u32, ) -> Weight;
+	fn fund_sponsorship() -> Weight;
+	fn withdraw_sponsorship() -> Weight;
+	fn sponsor_account() -> Weight;
+	fn unsponsor_account() -> Weight;
+}
+
+/// Placeholder weights for pallet_poe, see the module docs.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_claim(l: u32, ) -> Weight {
+		Weight::from_parts(46_000_000, 3804)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: PoeModule ClaimHistory (r:0 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: PoeModule ClaimLocks (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn revoke_claim(l: u32, ) -> Weight {
+		Weight::from_parts(95_000_000, 17163)
+			.saturating_add(Weight::from_parts(5_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn transfer_claim(l: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7397)
+			.saturating_add(Weight::from_parts(4_200, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingCosigns (r:n w:n)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// Storage: PoeModule DepositPayers (r:0 w:n)
+	/// Storage: System Account (r:1 + n w:1 + n)
+	/// The range of component `n` is `[1, 100]`.
+	fn create_claims(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 990)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: PoeModule ClaimExpiry (r:n w:n)
+	/// Storage: PoeModule ExpiryQueue (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:n)
+	/// Storage: PoeModule ClaimHistory (r:0 w:n)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:n w:n)
+	/// Storage: PoeModule ClaimSigners (r:0 w:n)
+	/// Storage: PoeModule SupersededBy (r:n w:n)
+	/// Storage: PoeModule Supersedes (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:n)
+	/// Storage: PoeModule ClaimLocks (r:n w:n)
+	/// Storage: PoeModule DepositPayers (r:n w:n)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// The range of component `n` is `[1, 100]`.
+	fn revoke_claims(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 990)
+			.saturating_add(Weight::from_parts(87_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 15_488).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:0)
+	/// Storage: PoeModule ClaimHistory (r:n w:n)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:0)
+	/// Storage: PoeModule ClaimLocks (r:n w:0)
+	/// Storage: PoeModule DepositPayers (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn transfer_claims(n: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(Weight::from_parts(53_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn anchor_root() -> Weight {
+		Weight::from_parts(27_000_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_anchor() -> Weight {
+		Weight::from_parts(28_000_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:0 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_expiring_claim(l: u32, ) -> Weight {
+		Weight::from_parts(69_000_000, 16478)
+			.saturating_add(Weight::from_parts(4_700, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:0 w:n)
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:n w:n)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:n)
+	/// Storage: PoeModule ClaimHistory (r:0 w:n)
+	/// Storage: PoeModule ClaimOperators (r:0 w:n)
+	/// Storage: PoeModule ClaimSigners (r:0 w:n)
+	/// Storage: PoeModule SupersededBy (r:n w:n)
+	/// Storage: PoeModule Supersedes (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:n)
+	/// Storage: PoeModule ClaimLocks (r:0 w:n)
+	/// Storage: PoeModule DepositPayers (r:n w:n)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_claims(n: u32, ) -> Weight {
+		Weight::from_parts(5_100_000, 16478)
+			.saturating_add(Weight::from_parts(56_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn offer_claim(l: u32, ) -> Weight {
+		Weight::from_parts(28_000_000, 3804)
+			.saturating_add(Weight::from_parts(2_200, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn accept_claim(l: u32, ) -> Weight {
+		Weight::from_parts(71_000_000, 7397)
+			.saturating_add(Weight::from_parts(6_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn cancel_offer(l: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3808)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_claim_metadata() -> Weight {
+		Weight::from_parts(35_000_000, 4217)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	fn approve_operator() -> Weight {
+		Weight::from_parts(25_000_000, 4253)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		Weight::from_parts(26_000_000, 4253)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule PendingCosigns (r:1 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn propose_cosigned_claim() -> Weight {
+		Weight::from_parts(51_000_000, 4817)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	fn cosign_claim() -> Weight {
+		Weight::from_parts(49_000_000, 4817)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_cosigned_claim() -> Weight {
+		Weight::from_parts(37_000_000, 4817)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:0)
+	/// Storage: PoeModule AttesterNonces (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_claim_attested(l: u32, ) -> Weight {
+		Weight::from_parts(77_000_000, 4817)
+			.saturating_add(Weight::from_parts(4_200, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:1)
+	fn register_attester() -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:1)
+	fn remove_attester() -> Weight {
+		Weight::from_parts(17_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule Supersedes (r:0 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn supersede_claim(l: u32, ) -> Weight {
+		Weight::from_parts(69_000_000, 4817)
+			.saturating_add(Weight::from_parts(3_300, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule CommitmentCount (r:1 w:1)
+	fn commit_claim() -> Weight {
+		Weight::from_parts(35_000_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:2 w:2)
+	/// Storage: PoeModule DepositPayers (r:1 w:2)
+	/// Storage: PoeModule CommitmentCount (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimHistory (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Storage: PoeModule ClaimLocks (r:0 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn reveal_claim(l: u32, ) -> Weight {
+		Weight::from_parts(99_000_000, 5078)
+			.saturating_add(Weight::from_parts(4_500, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: PoeModule Commitments (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: PoeModule CommitmentCount (r:n w:n)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_commitments(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 5078)
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule NextNamespaceId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule Namespaces (r:0 w:1)
+	fn create_namespace() -> Weight {
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	fn set_namespace_open() -> Weight {
+		Weight::from_parts(16_000_000, 3526)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:1)
+	fn add_namespace_writer() -> Weight {
+		Weight::from_parts(19_000_000, 7051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:1)
+	fn remove_namespace_writer() -> Weight {
+		Weight::from_parts(20_000_000, 7051)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:0)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(37_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_300, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn revoke_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(34_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_700, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn transfer_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(46_000_000, 7409)
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_namespace() -> Weight {
+		Weight::from_parts(28_000_000, 3816)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn challenge_claim(l: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 3816)
+			.saturating_add(Weight::from_parts(3_100, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn resolve_challenge(l: u32, ) -> Weight {
+		Weight::from_parts(91_000_000, 7441)
+			.saturating_add(Weight::from_parts(7_900, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn lock_claim(l: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	fn fund_sponsorship() -> Weight {
+		Weight::from_parts(30_000_000, 7122)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn withdraw_sponsorship() -> Weight {
+		Weight::from_parts(32_000_000, 7122)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Sponsorships (r:1 w:1)
+	fn sponsor_account() -> Weight {
+		Weight::from_parts(15_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Sponsorships (r:1 w:1)
+	fn unsponsor_account() -> Weight {
+		Weight::from_parts(16_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_claim(l: u32, ) -> Weight {
+		Weight::from_parts(46_000_000, 3804)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: PoeModule ClaimHistory (r:0 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: PoeModule ClaimLocks (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn revoke_claim(l: u32, ) -> Weight {
+		Weight::from_parts(95_000_000, 17163)
+			.saturating_add(Weight::from_parts(5_400, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn transfer_claim(l: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7397)
+			.saturating_add(Weight::from_parts(4_200, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingCosigns (r:n w:n)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// Storage: PoeModule DepositPayers (r:0 w:n)
+	/// Storage: System Account (r:1 + n w:1 + n)
+	/// The range of component `n` is `[1, 100]`.
+	fn create_claims(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 990)
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: PoeModule ClaimExpiry (r:n w:n)
+	/// Storage: PoeModule ExpiryQueue (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:n)
+	/// Storage: PoeModule ClaimHistory (r:0 w:n)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:n w:n)
+	/// Storage: PoeModule ClaimSigners (r:0 w:n)
+	/// Storage: PoeModule SupersededBy (r:n w:n)
+	/// Storage: PoeModule Supersedes (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:n)
+	/// Storage: PoeModule ClaimLocks (r:n w:n)
+	/// Storage: PoeModule DepositPayers (r:n w:n)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// The range of component `n` is `[1, 100]`.
+	fn revoke_claims(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 990)
+			.saturating_add(Weight::from_parts(87_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 15_488).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:0)
+	/// Storage: PoeModule ClaimHistory (r:n w:n)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:0)
+	/// Storage: PoeModule ClaimLocks (r:n w:0)
+	/// Storage: PoeModule DepositPayers (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn transfer_claims(n: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(Weight::from_parts(53_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn anchor_root() -> Weight {
+		Weight::from_parts(27_000_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Anchors (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_anchor() -> Weight {
+		Weight::from_parts(28_000_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:0 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_expiring_claim(l: u32, ) -> Weight {
+		Weight::from_parts(69_000_000, 16478)
+			.saturating_add(Weight::from_parts(4_700, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:0 w:n)
+	/// Storage: PoeModule Proofs (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: PoeModule ClaimCount (r:n w:n)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:n)
+	/// Storage: PoeModule PendingTransfers (r:0 w:n)
+	/// Storage: PoeModule ClaimMetadataOf (r:n w:n)
+	/// Storage: PoeModule ClaimHistory (r:0 w:n)
+	/// Storage: PoeModule ClaimOperators (r:0 w:n)
+	/// Storage: PoeModule ClaimSigners (r:0 w:n)
+	/// Storage: PoeModule SupersededBy (r:n w:n)
+	/// Storage: PoeModule Supersedes (r:n w:n)
+	/// Storage: PoeModule Challenges (r:n w:n)
+	/// Storage: PoeModule ClaimLocks (r:0 w:n)
+	/// Storage: PoeModule DepositPayers (r:n w:n)
+	/// Storage: PoeModule SponsorBudgets (r:n w:n)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_claims(n: u32, ) -> Weight {
+		Weight::from_parts(5_100_000, 16478)
+			.saturating_add(Weight::from_parts(56_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_814).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn offer_claim(l: u32, ) -> Weight {
+		Weight::from_parts(28_000_000, 3804)
+			.saturating_add(Weight::from_parts(2_200, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn accept_claim(l: u32, ) -> Weight {
+		Weight::from_parts(71_000_000, 7397)
+			.saturating_add(Weight::from_parts(6_400, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// Storage: PoeModule Operators (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:0)
+	/// The range of component `l` is `[0, 256]`.
+	fn cancel_offer(l: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3808)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_claim_metadata() -> Weight {
+		Weight::from_parts(35_000_000, 4217)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	fn approve_operator() -> Weight {
+		Weight::from_parts(25_000_000, 4253)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimOperators (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		Weight::from_parts(26_000_000, 4253)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule PendingCosigns (r:1 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn propose_cosigned_claim() -> Weight {
+		Weight::from_parts(51_000_000, 4817)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	fn cosign_claim() -> Weight {
+		Weight::from_parts(49_000_000, 4817)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_cosigned_claim() -> Weight {
+		Weight::from_parts(37_000_000, 4817)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:0)
+	/// Storage: PoeModule AttesterNonces (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_claim_attested(l: u32, ) -> Weight {
+		Weight::from_parts(77_000_000, 4817)
+			.saturating_add(Weight::from_parts(4_200, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:1)
+	fn register_attester() -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Attesters (r:1 w:1)
+	fn remove_attester() -> Weight {
+		Weight::from_parts(17_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Proofs (r:2 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule Supersedes (r:0 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: PoeModule DepositPayers (r:0 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn supersede_claim(l: u32, ) -> Weight {
+		Weight::from_parts(69_000_000, 4817)
+			.saturating_add(Weight::from_parts(3_300, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule CommitmentCount (r:1 w:1)
+	fn commit_claim() -> Weight {
+		Weight::from_parts(35_000_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Commitments (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule PendingCosigns (r:1 w:1)
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule ClaimCount (r:1 w:1)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Storage: PoeModule ActiveSponsorship (r:1 w:0)
+	/// Storage: PoeModule SponsorBudgets (r:2 w:2)
+	/// Storage: PoeModule DepositPayers (r:1 w:2)
+	/// Storage: PoeModule CommitmentCount (r:1 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:1)
+	/// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimHistory (r:0 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule ClaimSigners (r:0 w:1)
+	/// Storage: PoeModule SupersededBy (r:1 w:2)
+	/// Storage: PoeModule Supersedes (r:1 w:2)
+	/// Storage: PoeModule ClaimLocks (r:0 w:1)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn reveal_claim(l: u32, ) -> Weight {
+		Weight::from_parts(99_000_000, 5078)
+			.saturating_add(Weight::from_parts(4_500, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
+	}
+	/// Storage: PoeModule CommitmentQueue (r:1 w:1)
+	/// Storage: PoeModule Commitments (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: PoeModule CommitmentCount (r:n w:n)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_commitments(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 5078)
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(n.into()))
+	}
+	/// Storage: PoeModule NextNamespaceId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule Namespaces (r:0 w:1)
+	fn create_namespace() -> Weight {
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	fn set_namespace_open() -> Weight {
+		Weight::from_parts(16_000_000, 3526)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:1)
+	fn add_namespace_writer() -> Weight {
+		Weight::from_parts(19_000_000, 7051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:1)
+	fn remove_namespace_writer() -> Weight {
+		Weight::from_parts(20_000_000, 7051)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:0)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn create_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(37_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_300, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn revoke_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(34_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_700, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule NamespacedClaimCount (r:2 w:2)
+	/// The range of component `l` is `[0, 256]`.
+	fn transfer_namespaced_claim(l: u32, ) -> Weight {
+		Weight::from_parts(46_000_000, 7409)
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Namespaces (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule NamespaceWriters (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_namespace() -> Weight {
+		Weight::from_parts(28_000_000, 3816)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn challenge_claim(l: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 3816)
+			.saturating_add(Weight::from_parts(3_100, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Challenges (r:1 w:1)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: PoeModule ClaimHistory (r:1 w:1)
+	/// Storage: PoeModule ClaimMetadataOf (r:1 w:0)
+	/// Storage: PoeModule ClaimCount (r:2 w:2)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// Storage: PoeModule ClaimOperators (r:0 w:1)
+	/// Storage: PoeModule ClaimExpiry (r:1 w:0)
+	/// Storage: PoeModule DepositPayers (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn resolve_challenge(l: u32, ) -> Weight {
+		Weight::from_parts(91_000_000, 7441)
+			.saturating_add(Weight::from_parts(7_900, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Storage: PoeModule ClaimLocks (r:1 w:1)
+	/// The range of component `l` is `[0, 256]`.
+	fn lock_claim(l: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3816)
+			.saturating_add(Weight::from_parts(2_100, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	fn fund_sponsorship() -> Weight {
+		Weight::from_parts(30_000_000, 7122)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule SponsorBudgets (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn withdraw_sponsorship() -> Weight {
+		Weight::from_parts(32_000_000, 7122)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Sponsorships (r:1 w:1)
+	fn sponsor_account() -> Weight {
+		Weight::from_parts(15_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PoeModule Sponsorships (r:1 w:1)
+	fn unsponsor_account() -> Weight {
+		Weight::from_parts(16_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -15,7 +15,7 @@ use sp_runtime::{
 	traits::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -276,10 +276,122 @@ impl pallet_template::Config for Runtime {
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	/// Maximum length in bytes of a proof-of-existence claim.
+	///
+	/// Backed by a well-known storage key so governance can tune it with `system.set_storage`
+	/// without a runtime upgrade. It should only ever be raised: stored claims longer than a
+	/// lowered bound can no longer be decoded.
+	pub storage MaxClaimLength: u32 = 256;
+	/// Base deposit reserved for every proof-of-existence claim.
+	pub const ClaimDepositBase: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	/// Additional deposit reserved per byte of claim data.
+	pub const ClaimDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+	/// Maximum number of claims handled by a single batch call.
+	pub const MaxClaimsPerBatch: u32 = 100;
+	/// Claims may be given an expiry of at most one year.
+	pub const MaxClaimLifetime: BlockNumber = 365 * DAYS;
+	/// Maximum number of claims expiring in the same block.
+	pub const MaxExpiringPerBlock: u32 = 50;
+	/// Maximum number of claims a single account may own.
+	pub const MaxClaimsPerAccount: u32 = 10_000;
+	/// Keep the one-step `transfer_claim` available for existing clients; set to `false` to
+	/// require recipients to accept transfers through `offer_claim`/`accept_claim`.
+	pub const AllowDirectTransfer: bool = true;
+	/// Maximum length of the MIME type in claim metadata.
+	pub const MaxContentTypeLength: u32 = 64;
+	/// Maximum length of the human-readable description in claim metadata.
+	pub const MaxDescriptionLength: u32 = 256;
+	/// Maximum length of the off-chain URI (e.g. an IPFS CID) in claim metadata.
+	pub const MaxUriLength: u32 = 128;
+	/// Base deposit reserved for claim metadata.
+	pub const MetadataDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	/// Additional deposit reserved per byte of encoded claim metadata.
+	pub const MetadataDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+	/// Maximum number of previous owners recorded per claim.
+	pub const MaxHistoryLength: u32 = 100;
+	/// Maximum number of operators per account and per claim.
+	pub const MaxOperators: u32 = 16;
+	/// Maximum number of co-signers required by a single claim.
+	pub const MaxSigners: u32 = 16;
+	/// Co-signed claims must collect all signatures within 30 days.
+	pub const MaxCosignPeriod: BlockNumber = 30 * DAYS;
+	/// Attested claims are relayed as unsigned transactions; keep them below operational ones.
+	pub const PoeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	/// Deposit reserved for a claim commitment until it is revealed or expires.
+	pub const CommitmentDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	/// Committed claims must be revealed within 30 days.
+	pub const RevealPeriod: BlockNumber = 30 * DAYS;
+	/// Maximum number of commitments expiring in the same block.
+	pub const MaxCommitmentsPerBlock: u32 = 50;
+	/// Maximum number of unrevealed commitments per account.
+	pub const MaxCommitmentsPerAccount: u32 = 5;
+	/// Deposit reserved for registering a claim namespace.
+	pub const NamespaceDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	/// Bond reserved by an account challenging someone else's claim.
+	pub const ChallengeBond: Balance = 100 * EXISTENTIAL_DEPOSIT;
+}
+
+/// Key type of the attester key generated for pallet-poe benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+const POE_BENCHMARK_KEY: KeyTypeId = KeyTypeId(*b"poe!");
+
+/// Signs attestations with a keystore-generated sr25519 key when benchmarking pallet-poe.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<AccountId, Signature> for PoeBenchmarkHelper {
+	fn attester() -> AccountId {
+		let public = frame_support::sp_io::crypto::sr25519_generate(POE_BENCHMARK_KEY, None);
+		sp_runtime::MultiSigner::from(public).into_account()
+	}
+
+	fn sign(attester: &AccountId, message: &[u8]) -> Signature {
+		let public = sp_core::sr25519::Public::from_raw(attester.clone().into());
+		frame_support::sp_io::crypto::sr25519_sign(POE_BENCHMARK_KEY, &public, message)
+			.expect("attester key was generated in the keystore; qed")
+			.into()
+	}
+}
+
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type MaxClaimLength = ();
+	type MaxClaimLength = MaxClaimLength;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxClaimsPerBatch = MaxClaimsPerBatch;
+	type MaxClaimLifetime = MaxClaimLifetime;
+	type MaxExpiringPerBlock = MaxExpiringPerBlock;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type AllowDirectTransfer = AllowDirectTransfer;
+	type MaxContentTypeLength = MaxContentTypeLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxUriLength = MaxUriLength;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type MaxHistoryLength = MaxHistoryLength;
+	type MaxOperators = MaxOperators;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxSigners = MaxSigners;
+	type MaxCosignPeriod = MaxCosignPeriod;
+	type AttesterPublic = <Signature as Verify>::Signer;
+	type AttesterSignature = Signature;
+	type UnsignedPriority = PoeUnsignedPriority;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PoeBenchmarkHelper;
+	type CommitmentDeposit = CommitmentDeposit;
+	type RevealPeriod = RevealPeriod;
+	type MaxCommitmentsPerBlock = MaxCommitmentsPerBlock;
+	type MaxCommitmentsPerAccount = MaxCommitmentsPerAccount;
+	type NamespaceDeposit = NamespaceDeposit;
+	type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+	type ChallengeBond = ChallengeBond;
+	// Slashed bonds and deposits are burned.
+	type Slashed = ();
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -319,7 +431,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_poe::ChargeSponsoredFee<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -327,6 +439,13 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations applied on the next runtime upgrade.
+pub type Migrations = (
+	pallet_poe::migrations::v1::MigrateToV1<Runtime>,
+	pallet_poe::migrations::v2::MigrateToV2<Runtime>,
+	pallet_poe::migrations::v3::MigrateToV3<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -334,6 +453,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -348,6 +468,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_poe, PoeModule]
 	);
 }
 
@@ -519,6 +640,60 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe::runtime_api::PoeApi<Block, AccountId, BlockNumber, Balance, Hash> for Runtime {
+		fn get_claim(
+			namespace: pallet_poe::NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<pallet_poe::ClaimDetails<AccountId, BlockNumber, Balance>> {
+			PoeModule::claim_details(namespace, claim)
+		}
+
+		fn claim_history(claim: Vec<u8>) -> Vec<pallet_poe::OwnershipRecord<AccountId, BlockNumber>> {
+			PoeModule::claim_history(claim)
+		}
+
+		fn claim_versions(claim: Vec<u8>) -> Vec<Vec<u8>> {
+			PoeModule::claim_versions(claim)
+		}
+
+		fn verify_claim(namespace: pallet_poe::NamespaceId, claim: Vec<u8>, owner: AccountId) -> bool {
+			PoeModule::verify_claim(namespace, claim, &owner)
+		}
+
+		fn claim_count(who: AccountId) -> u32 {
+			PoeModule::claim_count(&who)
+		}
+
+		fn claims_of(who: AccountId, offset: u32, limit: u32) -> Vec<Vec<u8>> {
+			PoeModule::claims_of(&who, offset, limit)
+				.into_iter()
+				.map(|claim| claim.into_inner())
+				.collect()
+		}
+
+		fn verify_anchored_leaf(root: Hash, leaf: Hash, leaf_index: u32, proof: Vec<Hash>) -> bool {
+			PoeModule::verify_anchored_leaf(root, leaf, leaf_index, &proof)
+		}
+
+		fn get_namespace(namespace: pallet_poe::NamespaceId) -> Option<pallet_poe::NamespaceInfo<AccountId, Balance>> {
+			PoeModule::namespaces(namespace)
+		}
+
+		fn get_namespaced_claim(
+			namespace: pallet_poe::NamespaceId,
+			claim: Vec<u8>,
+		) -> Option<pallet_poe::ClaimInfo<AccountId, BlockNumber, Balance>> {
+			PoeModule::namespaced_claim(namespace, claim)
+		}
+
+		fn namespace_claims(namespace: pallet_poe::NamespaceId, offset: u32, limit: u32) -> Vec<Vec<u8>> {
+			PoeModule::namespace_claims(namespace, offset, limit)
+				.into_iter()
+				.map(|claim| claim.into_inner())
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -586,10 +761,78 @@ impl_runtime_apis! {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::traits::WhitelistedStorageKeys;
+	use frame_support::{assert_ok, traits::WhitelistedStorageKeys, BoundedVec};
+	use pallet_poe::ClaimInfo;
 	use sp_core::hexdisplay::HexDisplay;
+	use sp_runtime::AccountId32;
 	use std::collections::HashSet;
 
+	const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+	const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let storage = GenesisConfig {
+			balances: BalancesConfig {
+				balances: vec![(ALICE, 1_000_000_000), (BOB, 1_000_000_000)],
+			},
+			..Default::default()
+		}
+		.build_storage()
+		.unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn claim_of_len(len: usize) -> BoundedVec<u8, MaxClaimLength> {
+		BoundedVec::try_from(vec![7u8; len]).unwrap()
+	}
+
+	#[test]
+	fn poe_claims_round_trip_on_runtime() {
+		new_test_ext().execute_with(|| {
+			for len in [32, 64] {
+				let claim = claim_of_len(len);
+				let deposit = PoeModule::claim_deposit(len);
+
+				assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(ALICE), claim.clone()));
+				assert_eq!(
+					PoeModule::proofs(pallet_poe::DEFAULT_NAMESPACE, &claim),
+					Some(ClaimInfo { owner: ALICE, created_at: 1, updated_at: 1, deposit })
+				);
+				assert_eq!(Balances::reserved_balance(ALICE), deposit);
+
+				assert_ok!(PoeModule::transfer_claim(
+					RuntimeOrigin::signed(ALICE),
+					BOB,
+					claim.clone()
+				));
+				assert_eq!(
+					PoeModule::proofs(pallet_poe::DEFAULT_NAMESPACE, &claim),
+					Some(ClaimInfo { owner: BOB, created_at: 1, updated_at: 1, deposit })
+				);
+				assert_eq!(Balances::reserved_balance(ALICE), 0);
+				assert_eq!(Balances::reserved_balance(BOB), deposit);
+
+				assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), claim.clone()));
+				assert_eq!(PoeModule::proofs(pallet_poe::DEFAULT_NAMESPACE, &claim), None);
+				assert_eq!(Balances::reserved_balance(BOB), 0);
+			}
+		});
+	}
+
+	#[test]
+	fn poe_max_claim_length_is_tunable() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(MaxClaimLength::get(), 256);
+			assert!(BoundedVec::<u8, MaxClaimLength>::try_from(vec![0u8; 257]).is_err());
+
+			MaxClaimLength::set(&16);
+			assert_eq!(MaxClaimLength::get(), 16);
+			assert!(BoundedVec::<u8, MaxClaimLength>::try_from(vec![0u8; 32]).is_err());
+		});
+	}
+
 	#[test]
 	fn check_whitelist() {
 		let whitelist: HashSet<String> = AllPalletsWithSystem::whitelisted_storage_keys()